@@ -0,0 +1,182 @@
+// Unlike the "same input" benchmarks in my_benchmark.rs, these benchmarks iterate over
+// large pre-generated arrays of arguments, so that the branch predictor can't learn the
+// outcome of the `a > b` branch in `midpoint_via_cpp_20_std_implementation` unless the
+// distribution of the arguments makes it predictable:
+//
+// * "random": the order of the arguments is random, i.e. the branch is unpredictable;
+// * "sorted": the first argument never exceeds the second one, i.e. the branch is
+//   always taken the same way;
+// * "alternating": the order of the arguments alternates, which is predictable
+//   only for the branch predictors that track the history of the branch;
+// * "mixed-sign": the arguments of signed integers have different signs and
+//   random order, which is also the worst case for the naive algorithms.
+//
+// The throughput is reported in elements (i.e. midpoints) per second.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use midpoint::{
+    ConstantTimeMidpointExt, MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt,
+    MidpointViaCarryRotateExt, MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaOverflowingAddExt, MidpointViaPrimitivePromotionExt,
+};
+
+// The generator is shared with the verification tests of the library, which also
+// generate 128-bit integers
+#[allow(dead_code)]
+#[path = "../src/xorshift.rs"]
+mod xorshift;
+
+use crate::xorshift::XorShift64;
+
+const PAIRS_COUNT: usize = 4096;
+
+macro_rules! impl_distributions_fn {
+    ($fn_name:ident, $t:ty) => {
+        fn $fn_name() -> Vec<(&'static str, Vec<($t, $t)>)> {
+            let mut rng = XorShift64(0x2545_F491_4F6C_DD1D);
+            let random: Vec<($t, $t)> = (0..PAIRS_COUNT)
+                .map(|_| (rng.next_u64() as $t, rng.next_u64() as $t))
+                .collect();
+            let sorted = random
+                .iter()
+                .map(|&(a, b)| if a <= b { (a, b) } else { (b, a) })
+                .collect();
+            let alternating = random
+                .iter()
+                .enumerate()
+                .map(|(i, &(a, b))| {
+                    let (min, max) = if a <= b { (a, b) } else { (b, a) };
+                    if i % 2 == 0 {
+                        (min, max)
+                    } else {
+                        (max, min)
+                    }
+                })
+                .collect();
+            let mut distributions = vec![
+                ("random", random),
+                ("sorted", sorted),
+                ("alternating", alternating),
+            ];
+            // Type limit comparisons are deemed useless for unsigned types
+            // by the compiler but they are necessary for signed types
+            #[allow(unused_comparisons)]
+            let is_signed = <$t>::MIN < 0;
+            if is_signed {
+                let mixed_sign = (0..PAIRS_COUNT)
+                    .map(|_| {
+                        let bits = rng.next_u64() as $t;
+                        // The highest bit is the sign bit
+                        let (negative, non_negative) = (bits | <$t>::MIN, bits & <$t>::MAX);
+                        if rng.next_u64() % 2 == 0 {
+                            (negative, non_negative)
+                        } else {
+                            (non_negative, negative)
+                        }
+                    })
+                    .collect();
+                distributions.push(("mixed-sign", mixed_sign));
+            }
+            distributions
+        }
+    };
+}
+
+impl_distributions_fn!(distributions_for_u32, u32);
+impl_distributions_fn!(distributions_for_i32, i32);
+impl_distributions_fn!(distributions_for_u64, u64);
+impl_distributions_fn!(distributions_for_i64, i64);
+
+macro_rules! bench_fn {
+    // Black box prevents the compiler from vectorizing the loop or computing
+    // the midpoints at compile time but it doesn't affect branch prediction
+    ($group:ident, $distribution:ident, $pairs:ident, $t:ty, $fn_name:ident) => {
+        $group.bench_with_input(
+            BenchmarkId::new(stringify!($fn_name), $distribution),
+            $pairs,
+            |b, pairs: &Vec<($t, $t)>| {
+                b.iter(|| {
+                    for (a, b) in black_box(pairs.as_slice()) {
+                        black_box(a.$fn_name(b));
+                    }
+                })
+            },
+        );
+    };
+}
+
+macro_rules! bench_common_fns {
+    ($group:ident, $distribution:ident, $pairs:ident, $t:ty) => {
+        bench_fn!($group, $distribution, $pairs, $t, midpoint_via_bitwise_ops);
+        bench_fn!(
+            $group,
+            $distribution,
+            $pairs,
+            $t,
+            midpoint_via_cpp_20_std_implementation
+        );
+        bench_fn!(
+            $group,
+            $distribution,
+            $pairs,
+            $t,
+            midpoint_via_dietz_formula
+        );
+        bench_fn!(
+            $group,
+            $distribution,
+            $pairs,
+            $t,
+            midpoint_via_overflowing_add
+        );
+        bench_fn!($group, $distribution, $pairs, $t, midpoint_via_abs_diff);
+        bench_fn!($group, $distribution, $pairs, $t, ct_midpoint_toward_lhs);
+        bench_fn!(
+            $group,
+            $distribution,
+            $pairs,
+            $t,
+            midpoint_via_primitive_promotion
+        );
+    };
+}
+
+macro_rules! benchmark_for_t_on_distributions {
+    ($benchmark_name:ident, $t:ty, $distributions_fn:ident $(, $unsigned_only_fn:ident)?) => {
+        fn $benchmark_name(c: &mut Criterion) {
+            let mut group = c.benchmark_group(concat!(stringify!($t), " ", "distributions"));
+            group.throughput(Throughput::Elements(PAIRS_COUNT as u64));
+            for (distribution, pairs) in $distributions_fn() {
+                let pairs = &pairs;
+                bench_common_fns!(group, distribution, pairs, $t);
+                $(bench_fn!(group, distribution, pairs, $t, $unsigned_only_fn);)?
+            }
+            group.finish();
+        }
+    };
+}
+
+// MidpointViaCarryRotateExt is implemented only for unsigned integers
+benchmark_for_t_on_distributions!(
+    benchmark_for_u32,
+    u32,
+    distributions_for_u32,
+    midpoint_via_carry_rotate
+);
+benchmark_for_t_on_distributions!(benchmark_for_i32, i32, distributions_for_i32);
+benchmark_for_t_on_distributions!(
+    benchmark_for_u64,
+    u64,
+    distributions_for_u64,
+    midpoint_via_carry_rotate
+);
+benchmark_for_t_on_distributions!(benchmark_for_i64, i64, distributions_for_i64);
+
+criterion_group!(
+    benches,
+    benchmark_for_u32,
+    benchmark_for_i32,
+    benchmark_for_u64,
+    benchmark_for_i64,
+);
+criterion_main!(benches);
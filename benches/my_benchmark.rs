@@ -6,8 +6,10 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use midpoint::{
-    MidpointViaBitwiseOpsExt, MidpointViaCpp20StdImplementationExt,
-    MidpointViaNaiveMidpointDiffExt, MidpointViaPrimitivePromotionExt, NaiveMidpointExt,
+    MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt,
+    MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaNaiveMidpointDiffExt, MidpointViaOverflowingAddExt,
+    MidpointViaPrimitivePromotionExt, NaiveMidpointExt,
 };
 
 macro_rules! bench_fn {
@@ -55,6 +57,9 @@ macro_rules! bench_all_fns {
     ) => {
         bench_fn!($group, u128, midpoint_via_bitwise_ops, $a, $b);
         bench_fn!($group, u128, midpoint_via_cpp_20_std_implementation, $a, $b);
+        bench_fn!($group, u128, midpoint_via_dietz_formula, $a, $b);
+        bench_fn!($group, u128, midpoint_via_overflowing_add, $a, $b);
+        bench_fn!($group, u128, midpoint_via_abs_diff, $a, $b);
         bench_fn!($group, u128, unsafe midpoint_via_naive_midpoint_diff, $a, $b);
         bench_fn!($group, u128, unsafe naive_midpoint, $a, $b);
     };
@@ -66,6 +71,9 @@ macro_rules! bench_all_fns {
     ) => {
         bench_fn!($group, i128, midpoint_via_bitwise_ops, $a, $b);
         bench_fn!($group, i128, midpoint_via_cpp_20_std_implementation, $a, $b);
+        bench_fn!($group, i128, midpoint_via_dietz_formula, $a, $b);
+        bench_fn!($group, i128, midpoint_via_overflowing_add, $a, $b);
+        bench_fn!($group, i128, midpoint_via_abs_diff, $a, $b);
         bench_fn!($group, i128, unsafe midpoint_via_naive_midpoint_diff, $a, $b);
         bench_fn!($group, i128, unsafe naive_midpoint, $a, $b);
     };
@@ -77,17 +85,41 @@ macro_rules! bench_all_fns {
     ) => {
         bench_fn!($group, $t, midpoint_via_bitwise_ops, $a, $b);
         bench_fn!($group, $t, midpoint_via_cpp_20_std_implementation, $a, $b);
+        bench_fn!($group, $t, midpoint_via_dietz_formula, $a, $b);
+        bench_fn!($group, $t, midpoint_via_overflowing_add, $a, $b);
+        bench_fn!($group, $t, midpoint_via_abs_diff, $a, $b);
         bench_fn!($group, $t, unsafe midpoint_via_naive_midpoint_diff, $a, $b);
         bench_fn!($group, $t, unsafe naive_midpoint, $a, $b);
         bench_fn!($group, $t, midpoint_via_primitive_promotion, $a, $b);
     };
 }
 
+// MidpointViaCarryRotateExt is implemented only for unsigned integers
+macro_rules! bench_unsigned_only_fns {
+    ($group:ident, u8, $a:expr, $b:expr) => {
+        bench_fn!($group, u8, midpoint_via_carry_rotate, $a, $b);
+    };
+    ($group:ident, u16, $a:expr, $b:expr) => {
+        bench_fn!($group, u16, midpoint_via_carry_rotate, $a, $b);
+    };
+    ($group:ident, u32, $a:expr, $b:expr) => {
+        bench_fn!($group, u32, midpoint_via_carry_rotate, $a, $b);
+    };
+    ($group:ident, u64, $a:expr, $b:expr) => {
+        bench_fn!($group, u64, midpoint_via_carry_rotate, $a, $b);
+    };
+    ($group:ident, u128, $a:expr, $b:expr) => {
+        bench_fn!($group, u128, midpoint_via_carry_rotate, $a, $b);
+    };
+    ($group:ident, $t:ty, $a:expr, $b:expr) => {};
+}
+
 macro_rules! benchmark_all_fns_for_t_on_same_input {
     ($benchmark_name:ident, $t:ident, $a:expr, $b:expr) => {
         fn $benchmark_name(c: &mut Criterion) {
             let mut group = c.benchmark_group(concat!(stringify!($t), " ", "same input"));
             bench_all_fns!(group, $t, 0, 20);
+            bench_unsigned_only_fns!(group, $t, 0, 20);
             group.finish();
         }
     };
@@ -0,0 +1,67 @@
+//! Warns when the enabled algorithm features provide more rounding modes than configured
+//! via the `MIDPOINT_MAX_ROUNDING_MODES` environment variable.
+//!
+//! As the design document notes, every rounding mode multiplies the number of the
+//! generated functions. The limit can be set, for example, in `.cargo/config.toml`:
+//!
+//! ```toml
+//! [env]
+//! MIDPOINT_MAX_ROUNDING_MODES = "1"
+//! ```
+//!
+//! Note that Cargo shows the warnings of the build scripts of the dependencies fetched
+//! from a registry only when invoked with `-vv`.
+
+use std::env;
+
+const MAX_ROUNDING_MODES_VAR: &str = "MIDPOINT_MAX_ROUNDING_MODES";
+
+include!("rounding_modes_by_feature.rs");
+
+fn is_feature_enabled(feature: &str) -> bool {
+    // Cargo exposes the enabled features as CARGO_FEATURE_<NAME> variables
+    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=rounding_modes_by_feature.rs");
+    println!("cargo:rerun-if-env-changed={MAX_ROUNDING_MODES_VAR}");
+
+    let max_rounding_modes = match env::var(MAX_ROUNDING_MODES_VAR) {
+        Ok(value) => match value.trim().parse::<usize>() {
+            Ok(max_rounding_modes) => max_rounding_modes,
+            Err(_) => {
+                println!(
+                    "cargo:warning={MAX_ROUNDING_MODES_VAR}={value:?} is not a non-negative integer and is ignored"
+                );
+                return;
+            }
+        },
+        Err(_) => return,
+    };
+
+    let mut rounding_modes: Vec<&str> = Vec::new();
+    let mut features: Vec<&str> = Vec::new();
+    for &(feature, modes) in ROUNDING_MODES_BY_FEATURE {
+        if !is_feature_enabled(feature) {
+            continue;
+        }
+        features.push(feature);
+        for &mode in modes {
+            if !rounding_modes.contains(&mode) {
+                rounding_modes.push(mode);
+            }
+        }
+    }
+
+    if rounding_modes.len() > max_rounding_modes {
+        println!(
+            "cargo:warning={} rounding modes ({}) are in use by the enabled features ({}) \
+             while {MAX_ROUNDING_MODES_VAR} is {max_rounding_modes}",
+            rounding_modes.len(),
+            rounding_modes.join(", "),
+            features.join(", "),
+        );
+    }
+}
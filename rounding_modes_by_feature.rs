@@ -0,0 +1,40 @@
+// Included by the build script and by the tests of `src/algorithm.rs`, which check
+// the table against the `ROUNDING` and `ROUNDING_MODES` constants of the algorithm traits
+
+/// Rounding modes of the integer midpoints provided by the algorithm features. The midpoints
+/// of IP addresses and ranges of primitive integers reuse the algorithms of `bitwise` and
+/// `cpp_20_std`, respectively
+const ROUNDING_MODES_BY_FEATURE: &[(&str, &[&str])] = &[
+    ("naive", &["TowardZero"]),
+    ("primitive_promotion", &["TowardZero"]),
+    ("bitwise", &["TowardZero"]),
+    ("naive_midpoint_diff", &["TowardLhs"]),
+    ("cpp_20_std", &["TowardLhs"]),
+    ("dietz", &["Down"]),
+    ("overflowing_add", &["Down"]),
+    ("abs_diff", &["Down"]),
+    ("carry_rotate", &["Down"]),
+    ("swar", &["Up", "Down"]),
+    (
+        "constant_time",
+        &[
+            "Up",
+            "Down",
+            "TowardZero",
+            "TowardLhs",
+            "TowardRhs",
+            "TiesToEven",
+        ],
+    ),
+    (
+        "exact",
+        &[
+            "Up",
+            "Down",
+            "TowardZero",
+            "TowardLhs",
+            "TowardRhs",
+            "TiesToEven",
+        ],
+    ),
+];
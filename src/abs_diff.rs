@@ -0,0 +1,85 @@
+use epui::EquisizedPrimitiveUnsignedIntExt as EPUI;
+
+use crate::RoundingMode;
+
+try_impl_generic_const_fn_for_trait!(MidpointViaAbsDiffExt::midpoint_via_abs_diff);
+
+/// Extension trait providing implementation of midpoint algorithm via
+/// [`abs_diff`](i32::abs_diff), i.e. `min(a, b) + abs_diff(a, b) / 2`.
+/// For primitive integers, the result is rounded down.
+pub trait MidpointViaAbsDiffExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::Down;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
+    /// Returns midpoint using algorithm based on [`abs_diff`](i32::abs_diff), i.e.
+    /// `min(a, b) + abs_diff(a, b) / 2`. For primitive integers, the result is rounded down.
+    ///
+    /// Unlike [`MidpointViaNaiveMidpointDiffExt`](crate::MidpointViaNaiveMidpointDiffExt),
+    /// the difference of the arguments never overflows, and unlike
+    /// [`MidpointViaCpp20StdImplementationExt`](crate::MidpointViaCpp20StdImplementationExt),
+    /// the order of the arguments doesn't matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaAbsDiffExt;
+    ///
+    /// let result: i32 = (-2).midpoint_via_abs_diff(&-3);
+    /// assert_eq!(result, -3);
+    /// ```
+    #[must_use]
+    fn midpoint_via_abs_diff(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+macro_rules! impl_midpoint_fn_for_t {
+    () => {
+        fn midpoint_via_abs_diff(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // At the time of writing, explicit dereferencing is necessary because
+            // `<&u8 as Add<&u8>>::add` is not yet stable as a const fn
+            // and requires `#![feature(const_ops)]`
+            //
+            // Rust unstable book entry:
+            // https://doc.rust-lang.org/beta/unstable-book/library-features/const-ops.html
+            let (lhs, rhs) = (*self, *rhs_ref);
+            // `Ord::min` is not a const fn. The selection is typically compiled
+            // to a conditional move rather than a branch
+            let min = if lhs < rhs { lhs } else { rhs };
+            let half_diff: <Self as EPUI>::EquisizedPrimitiveUnsignedInt = lhs.abs_diff(rhs) / 2;
+            // The half of the difference doesn't necessarily fit in the signed type but
+            // the midpoint does, so modular arithmetic yields the correct result
+            min.wrapping_add(half_diff as Self)
+        }
+    };
+}
+
+impl_for_all_prim_ints!(
+    trait = MidpointViaAbsDiffExt,
+    fn macro = impl_midpoint_fn_for_t
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaAbsDiffExt;
+
+    #[test]
+    fn midpoint_via_abs_diff_rounds_down_including_when_args_are_positive() {
+        let result: i32 = 3.midpoint_via_abs_diff(&2);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn midpoint_via_abs_diff_rounds_down_including_when_args_are_negative() {
+        let result: i32 = (-2).midpoint_via_abs_diff(&-3);
+        assert_eq!(result, -3);
+    }
+
+    #[test]
+    fn midpoint_via_abs_diff_doesnt_overflow_when_diff_exceeds_signed_range() {
+        assert_eq!(i8::MIN.midpoint_via_abs_diff(&i8::MAX), -1);
+        assert_eq!(i8::MAX.midpoint_via_abs_diff(&i8::MIN), -1);
+    }
+}
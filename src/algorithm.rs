@@ -0,0 +1,553 @@
+use core::fmt;
+
+use crate::naive_midpoint_diff::NaiveMidpointDiffPrecondition;
+use crate::{
+    MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt,
+    MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaNaiveMidpointDiffExt, MidpointViaOverflowingAddExt,
+    MidpointViaPrimitivePromotionExt, NaiveMidpointExt, RoundingMode,
+};
+
+const PRIM_INTS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Midpoint algorithm for primitive integers selectable at runtime, e.g. from
+/// a configuration file when the `serde` feature is enabled.
+///
+/// Every variant corresponds to an extension trait, to which [`midpoint_with`]
+/// dispatches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Algorithm {
+    /// [`NaiveMidpointExt`]
+    Naive,
+    /// [`MidpointViaPrimitivePromotionExt`]
+    PrimitivePromotion,
+    /// [`MidpointViaBitwiseOpsExt`]
+    BitwiseOps,
+    /// [`MidpointViaNaiveMidpointDiffExt`]
+    NaiveMidpointDiff,
+    /// [`MidpointViaCpp20StdImplementationExt`]
+    Cpp20Std,
+    /// [`MidpointViaDietzFormulaExt`]
+    DietzFormula,
+    /// [`MidpointViaOverflowingAddExt`]
+    OverflowingAdd,
+    /// [`MidpointViaAbsDiffExt`]
+    AbsDiff,
+    /// [`MidpointViaCarryRotateExt`]
+    CarryRotate,
+}
+
+impl Algorithm {
+    /// All algorithms, e.g. for comparing them side by side.
+    pub const ALL: [Algorithm; 9] = [
+        Algorithm::Naive,
+        Algorithm::PrimitivePromotion,
+        Algorithm::BitwiseOps,
+        Algorithm::NaiveMidpointDiff,
+        Algorithm::Cpp20Std,
+        Algorithm::DietzFormula,
+        Algorithm::OverflowingAdd,
+        Algorithm::AbsDiff,
+        Algorithm::CarryRotate,
+    ];
+
+    /// Returns the metadata of the algorithm from the [`ALGORITHMS`] registry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::BitwiseOps.info().trait_name, "MidpointViaBitwiseOpsExt");
+    /// ```
+    pub const fn info(self) -> &'static AlgorithmInfo {
+        // The registry lists the algorithms in the order of declaration
+        &REGISTRY[self as usize]
+    }
+
+    /// Returns the rounding behavior of the algorithm for primitive integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{Algorithm, RoundingMode};
+    ///
+    /// assert_eq!(Algorithm::Cpp20Std.rounding(), RoundingMode::TowardLhs);
+    /// ```
+    pub const fn rounding(self) -> RoundingMode {
+        self.info().rounding
+    }
+
+    /// Returns the safety precondition of the algorithm or [`None`] if the algorithm
+    /// is safe. [`midpoint_with`] checks the precondition before calling the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::Algorithm;
+    ///
+    /// assert!(Algorithm::Naive.precondition().is_some());
+    /// assert!(Algorithm::BitwiseOps.precondition().is_none());
+    /// ```
+    pub const fn precondition(self) -> Option<&'static str> {
+        self.info().precondition
+    }
+
+    /// Returns the names of the primitive integer types supported by the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::Algorithm;
+    ///
+    /// assert!(!Algorithm::PrimitivePromotion.supported_types().contains(&"u128"));
+    /// assert!(!Algorithm::CarryRotate.supported_types().contains(&"i32"));
+    /// ```
+    pub const fn supported_types(self) -> &'static [&'static str] {
+        self.info().supported_types
+    }
+}
+
+/// Metadata of a midpoint algorithm for primitive integers, see [`ALGORITHMS`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AlgorithmInfo {
+    /// The algorithm described by the metadata.
+    pub algorithm: Algorithm,
+    /// The name of the extension trait providing the algorithm.
+    pub trait_name: &'static str,
+    /// The name of the method of the extension trait.
+    pub method_name: &'static str,
+    /// The rounding behavior, i.e. the `ROUNDING` constant of the trait.
+    pub rounding: RoundingMode,
+    /// The `IS_COMMUTATIVE` constant of the trait.
+    pub is_commutative: bool,
+    /// The `IS_ODD_SYMMETRIC` constant of the trait.
+    pub is_odd_symmetric: bool,
+    /// The safety precondition or [`None`] if the algorithm is safe.
+    pub precondition: Option<&'static str>,
+    /// The names of the supported primitive integer types.
+    pub supported_types: &'static [&'static str],
+}
+
+macro_rules! algorithm_info {
+    (
+        $algorithm:ident,
+        <$t:ty as $trait_name:ident>::$method_name:ident,
+        precondition = $precondition:expr,
+        supported_types = $supported_types:expr
+    ) => {
+        AlgorithmInfo {
+            algorithm: Algorithm::$algorithm,
+            trait_name: stringify!($trait_name),
+            method_name: stringify!($method_name),
+            rounding: <$t as $trait_name>::ROUNDING,
+            is_commutative: <$t as $trait_name>::IS_COMMUTATIVE,
+            is_odd_symmetric: <$t as $trait_name>::IS_ODD_SYMMETRIC,
+            precondition: $precondition,
+            supported_types: $supported_types,
+        }
+    };
+}
+
+// The constants are read from the implementations for a supported type
+const REGISTRY: [AlgorithmInfo; 9] = [
+    algorithm_info!(
+        Naive,
+        <i32 as NaiveMidpointExt>::naive_midpoint,
+        precondition = Some("the sum of the arguments must fit in their type"),
+        supported_types = PRIM_INTS
+    ),
+    // u128 and i128 don't have a primitive promotion, while the primitive
+    // promotion for usize and isize is not provided by `primitive_promotion`
+    algorithm_info!(
+        PrimitivePromotion,
+        <i32 as MidpointViaPrimitivePromotionExt>::midpoint_via_primitive_promotion,
+        precondition = None,
+        supported_types = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"]
+    ),
+    algorithm_info!(
+        BitwiseOps,
+        <i32 as MidpointViaBitwiseOpsExt>::midpoint_via_bitwise_ops,
+        precondition = None,
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        NaiveMidpointDiff,
+        <i32 as MidpointViaNaiveMidpointDiffExt>::midpoint_via_naive_midpoint_diff,
+        precondition = Some(
+            "the difference of the second and the first argument must fit \
+             in the signed integer type of the same size"
+        ),
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        Cpp20Std,
+        <i32 as MidpointViaCpp20StdImplementationExt>::midpoint_via_cpp_20_std_implementation,
+        precondition = None,
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        DietzFormula,
+        <i32 as MidpointViaDietzFormulaExt>::midpoint_via_dietz_formula,
+        precondition = None,
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        OverflowingAdd,
+        <i32 as MidpointViaOverflowingAddExt>::midpoint_via_overflowing_add,
+        precondition = None,
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        AbsDiff,
+        <i32 as MidpointViaAbsDiffExt>::midpoint_via_abs_diff,
+        precondition = None,
+        supported_types = PRIM_INTS
+    ),
+    algorithm_info!(
+        CarryRotate,
+        <u32 as MidpointViaCarryRotateExt>::midpoint_via_carry_rotate,
+        precondition = None,
+        supported_types = &["u8", "u16", "u32", "u64", "u128", "usize"]
+    ),
+];
+
+/// Static registry of the midpoint algorithms for primitive integers and their metadata,
+/// so that generic code and tests can assert the semantics they rely on.
+///
+/// Every entry describes a single [`Algorithm`] with one rounding behavior. The traits
+/// offering several rounding behaviors, i.e. [`ExactMidpointExt`](crate::ExactMidpointExt),
+/// [`ConstantTimeMidpointExt`](crate::ConstantTimeMidpointExt) and the lane-wise
+/// [`MidpointViaSwarExt`](crate::MidpointViaSwarExt), are left out. They list their rounding
+/// behaviors in their `ROUNDING_MODES` constants instead.
+///
+/// # Example
+///
+/// ```
+/// use midpoint::ALGORITHMS;
+///
+/// let commutative: Vec<_> = ALGORITHMS
+///     .iter()
+///     .filter(|info| info.is_commutative)
+///     .map(|info| info.trait_name)
+///     .collect();
+/// assert!(commutative.contains(&"MidpointViaBitwiseOpsExt"));
+/// assert!(!commutative.contains(&"MidpointViaCpp20StdImplementationExt"));
+/// ```
+pub static ALGORITHMS: &[AlgorithmInfo] = &REGISTRY;
+
+/// Error returned by [`midpoint_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MidpointError {
+    /// The safety precondition of the algorithm doesn't hold for the arguments,
+    /// see [`Algorithm::precondition`].
+    PreconditionViolated(Algorithm),
+    /// The algorithm is not implemented for the type of the arguments,
+    /// see [`Algorithm::supported_types`].
+    UnsupportedType(Algorithm),
+}
+
+impl fmt::Display for MidpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MidpointError::PreconditionViolated(algorithm) => write!(
+                f,
+                "precondition of {algorithm:?} is violated: {}",
+                algorithm.precondition().unwrap_or_default()
+            ),
+            MidpointError::UnsupportedType(algorithm) => {
+                write!(f, "{algorithm:?} is not implemented for the type")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MidpointError {}
+
+/// Returns the midpoint computed by the algorithm selected at runtime.
+///
+/// Unlike the unsafe algorithms themselves, the function checks their safety
+/// preconditions and returns [`MidpointError::PreconditionViolated`] when they don't hold.
+///
+/// # Example
+///
+/// ```
+/// use midpoint::{midpoint_with, Algorithm, MidpointError};
+///
+/// assert_eq!(midpoint_with(Algorithm::Cpp20Std, &-3i32, &-2), Ok(-3));
+/// assert_eq!(
+///     midpoint_with(Algorithm::Naive, &u8::MAX, &1),
+///     Err(MidpointError::PreconditionViolated(Algorithm::Naive))
+/// );
+/// ```
+pub fn midpoint_with<T>(algorithm: Algorithm, lhs: &T, rhs: &T) -> Result<T, MidpointError>
+where
+    T: AlgorithmMidpointExt,
+{
+    lhs.midpoint_with(rhs, algorithm)
+}
+
+/// Extension trait providing midpoint computed by the algorithm selected at runtime.
+///
+/// See [`midpoint_with`].
+pub trait AlgorithmMidpointExt: Sized {
+    /// Returns the midpoint computed by the algorithm selected at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{Algorithm, AlgorithmMidpointExt, MidpointError};
+    ///
+    /// assert_eq!(7u64.midpoint_with(&2, Algorithm::DietzFormula), Ok(4));
+    /// assert_eq!(
+    ///     7i64.midpoint_with(&2, Algorithm::CarryRotate),
+    ///     Err(MidpointError::UnsupportedType(Algorithm::CarryRotate))
+    /// );
+    /// ```
+    fn midpoint_with(
+        &self, /*lhs_ref*/
+        rhs_ref: &Self,
+        algorithm: Algorithm,
+    ) -> Result<Self, MidpointError>;
+}
+
+macro_rules! call_if_supported {
+    (true, $algorithm:expr, $call:expr) => {
+        Ok($call)
+    };
+    (false, $algorithm:expr, $call:expr) => {
+        Err(MidpointError::UnsupportedType($algorithm))
+    };
+}
+
+macro_rules! impl_algorithm_midpoint_for_types {
+    ($($t:ty: promotion = $promotion:tt, carry_rotate = $carry_rotate:tt;)+) => {
+        $(
+            impl AlgorithmMidpointExt for $t {
+                fn midpoint_with(
+                    &self, /*lhs_ref*/
+                    rhs_ref: &Self,
+                    algorithm: Algorithm,
+                ) -> Result<Self, MidpointError> {
+                    let (lhs, rhs) = (*self, *rhs_ref);
+                    match algorithm {
+                        Algorithm::Naive => match lhs.checked_add(rhs) {
+                            // SAFETY: the sum of the arguments fits in their type
+                            Some(_) => Ok(unsafe { lhs.naive_midpoint(&rhs) }),
+                            None => Err(MidpointError::PreconditionViolated(algorithm)),
+                        },
+                        Algorithm::PrimitivePromotion => call_if_supported!(
+                            $promotion,
+                            algorithm,
+                            lhs.midpoint_via_primitive_promotion(&rhs)
+                        ),
+                        Algorithm::BitwiseOps => Ok(lhs.midpoint_via_bitwise_ops(&rhs)),
+                        Algorithm::NaiveMidpointDiff => {
+                            if lhs.does_diff_fit(&rhs) {
+                                // SAFETY: the difference of the arguments fits in the signed type
+                                Ok(unsafe { lhs.midpoint_via_naive_midpoint_diff(&rhs) })
+                            } else {
+                                Err(MidpointError::PreconditionViolated(algorithm))
+                            }
+                        }
+                        Algorithm::Cpp20Std => Ok(lhs.midpoint_via_cpp_20_std_implementation(&rhs)),
+                        Algorithm::DietzFormula => Ok(lhs.midpoint_via_dietz_formula(&rhs)),
+                        Algorithm::OverflowingAdd => Ok(lhs.midpoint_via_overflowing_add(&rhs)),
+                        Algorithm::AbsDiff => Ok(lhs.midpoint_via_abs_diff(&rhs)),
+                        Algorithm::CarryRotate => call_if_supported!(
+                            $carry_rotate,
+                            algorithm,
+                            lhs.midpoint_via_carry_rotate(&rhs)
+                        ),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_algorithm_midpoint_for_types!(
+    u8: promotion = true, carry_rotate = true;
+    u16: promotion = true, carry_rotate = true;
+    u32: promotion = true, carry_rotate = true;
+    u64: promotion = true, carry_rotate = true;
+    u128: promotion = false, carry_rotate = true;
+    usize: promotion = false, carry_rotate = true;
+    i8: promotion = true, carry_rotate = false;
+    i16: promotion = true, carry_rotate = false;
+    i32: promotion = true, carry_rotate = false;
+    i64: promotion = true, carry_rotate = false;
+    i128: promotion = false, carry_rotate = false;
+    isize: promotion = false, carry_rotate = false;
+);
+
+#[cfg(all(test, feature = "exact"))]
+mod tests {
+    #[cfg(feature = "constant_time")]
+    use crate::ConstantTimeMidpointExt;
+    #[cfg(feature = "swar")]
+    use crate::MidpointViaSwarExt;
+    use crate::{
+        midpoint_with, Algorithm, ExactMidpointExt, MidpointError, RoundingMode, ALGORITHMS,
+    };
+
+    include!("../rounding_modes_by_feature.rs");
+
+    fn rounding_mode_name(mode: RoundingMode) -> &'static str {
+        match mode {
+            RoundingMode::Up => "Up",
+            RoundingMode::Down => "Down",
+            RoundingMode::TowardZero => "TowardZero",
+            RoundingMode::TowardLhs => "TowardLhs",
+            RoundingMode::TowardRhs => "TowardRhs",
+            RoundingMode::TiesToEven => "TiesToEven",
+        }
+    }
+
+    #[test]
+    fn rounding_modes_counted_by_build_script_match_registry() {
+        let features = [
+            (Algorithm::Naive, "naive"),
+            (Algorithm::PrimitivePromotion, "primitive_promotion"),
+            (Algorithm::BitwiseOps, "bitwise"),
+            (Algorithm::NaiveMidpointDiff, "naive_midpoint_diff"),
+            (Algorithm::Cpp20Std, "cpp_20_std"),
+            (Algorithm::DietzFormula, "dietz"),
+            (Algorithm::OverflowingAdd, "overflowing_add"),
+            (Algorithm::AbsDiff, "abs_diff"),
+            (Algorithm::CarryRotate, "carry_rotate"),
+        ];
+        for info in ALGORITHMS {
+            let (_, feature) = features
+                .iter()
+                .find(|(algorithm, _)| *algorithm == info.algorithm)
+                .unwrap();
+            let (_, modes) = ROUNDING_MODES_BY_FEATURE
+                .iter()
+                .find(|(name, _)| name == feature)
+                .unwrap();
+            assert_eq!(*modes, [rounding_mode_name(info.rounding)], "{feature}");
+        }
+    }
+
+    fn assert_rounding_modes_match_build_script(feature: &str, modes: &[RoundingMode]) {
+        let (_, names) = ROUNDING_MODES_BY_FEATURE
+            .iter()
+            .find(|(name, _)| *name == feature)
+            .unwrap();
+        let modes = modes.iter().map(|mode| rounding_mode_name(*mode));
+        assert!(modes.eq(names.iter().copied()), "{feature}");
+    }
+
+    #[test]
+    fn rounding_modes_counted_by_build_script_match_multi_mode_traits() {
+        assert_rounding_modes_match_build_script(
+            "exact",
+            <i32 as ExactMidpointExt>::ROUNDING_MODES,
+        );
+        #[cfg(feature = "constant_time")]
+        assert_rounding_modes_match_build_script(
+            "constant_time",
+            <i32 as ConstantTimeMidpointExt>::ROUNDING_MODES,
+        );
+        #[cfg(feature = "swar")]
+        assert_rounding_modes_match_build_script(
+            "swar",
+            <u64 as MidpointViaSwarExt>::ROUNDING_MODES,
+        );
+    }
+
+    #[test]
+    fn midpoint_with_rounds_as_specified_by_metadata() {
+        for algorithm in Algorithm::ALL {
+            for (a, b) in [(2u8, 3u8), (3, 2), (0, 255), (255, 254), (100, 120)] {
+                let expected = a.midpoint_rounded(&b, algorithm.rounding());
+                match midpoint_with(algorithm, &a, &b) {
+                    Ok(result) => assert_eq!(result, expected, "{algorithm:?} {a} {b}"),
+                    Err(MidpointError::PreconditionViolated(_)) => {
+                        assert!(algorithm.precondition().is_some())
+                    }
+                    Err(MidpointError::UnsupportedType(_)) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn midpoint_with_checks_preconditions() {
+        let result = midpoint_with(Algorithm::NaiveMidpointDiff, &i32::MIN, &i32::MAX);
+        let expected = Err(MidpointError::PreconditionViolated(
+            Algorithm::NaiveMidpointDiff,
+        ));
+        assert_eq!(result, expected);
+        // The difference is exactly i32::MIN
+        let result = midpoint_with(Algorithm::NaiveMidpointDiff, &u32::MAX, &(i32::MAX as u32));
+        assert_eq!(result, Ok(0xBFFF_FFFF));
+    }
+
+    #[test]
+    fn midpoint_with_rejects_unsupported_types() {
+        for algorithm in Algorithm::ALL {
+            let is_supported = algorithm.supported_types().contains(&"i128");
+            let result = midpoint_with(algorithm, &-3i128, &-2);
+            assert_eq!(result.is_ok(), is_supported, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn registry_lists_algorithms_in_order_of_declaration() {
+        for (info, algorithm) in ALGORITHMS.iter().zip(Algorithm::ALL) {
+            assert_eq!(info.algorithm, algorithm);
+        }
+        assert_eq!(ALGORITHMS.len(), Algorithm::ALL.len());
+    }
+
+    #[test]
+    fn midpoint_with_has_properties_specified_by_registry() {
+        for info in ALGORITHMS {
+            let midpoint = |a: &i8, b: &i8| midpoint_with(info.algorithm, a, b);
+            for a in i8::MIN..=i8::MAX {
+                for b in i8::MIN..=i8::MAX {
+                    let Ok(result) = midpoint(&a, &b) else {
+                        continue;
+                    };
+                    if info.is_commutative {
+                        if let Ok(swapped) = midpoint(&b, &a) {
+                            assert_eq!(result, swapped, "{:?} {a} {b}", info.algorithm);
+                        }
+                    }
+                    if info.is_odd_symmetric {
+                        if let (Some(neg_a), Some(neg_b)) = (a.checked_neg(), b.checked_neg()) {
+                            if let Ok(of_negs) = midpoint(&neg_a, &neg_b) {
+                                assert_eq!(
+                                    Some(result),
+                                    of_negs.checked_neg(),
+                                    "{:?} {a} {b}",
+                                    info.algorithm
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn algorithm_is_deserialized_from_variant_name() {
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let deserializer: StrDeserializer<Error> = "Cpp20Std".into_deserializer();
+        assert_eq!(
+            Algorithm::deserialize(deserializer),
+            Ok(Algorithm::Cpp20Std)
+        );
+    }
+}
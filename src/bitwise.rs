@@ -1,3 +1,5 @@
+use crate::RoundingMode;
+
 try_impl_generic_const_fn_for_trait!(
     MidpointViaBitwiseOpsExt::midpoint_via_bitwise_ops
 );
@@ -6,6 +8,13 @@ try_impl_generic_const_fn_for_trait!(
 /// [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation).
 /// For primitive integers, the result is rounded towards zero.
 pub trait MidpointViaBitwiseOpsExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::TowardZero;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
     /// Returns midpoint using algorithm using
     /// [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation).
     /// For primitive integers, the result is rounded towards zero.
@@ -24,6 +33,9 @@ pub trait MidpointViaBitwiseOpsExt {
 
 macro_rules! impl_midpoint_fn_for_t {
     () => {
+        // Type limit comparisons are deemed useless for unsigned types
+        // by the compiler but they are necessary for signed types
+        #[allow(unused_comparisons)]
         fn midpoint_via_bitwise_ops(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
             // At the time of writing, explicit dereferencing is necessary because
             // `<&u8 as Add<&u8>>::add` is not yet stable as a const fn
@@ -32,10 +44,15 @@ macro_rules! impl_midpoint_fn_for_t {
             // Rust unstable book entry:
             // https://doc.rust-lang.org/beta/unstable-book/library-features/const-ops.html
             let (lhs, rhs) = (*self, *rhs_ref);
-            // Equivalent to SAR or SHR depending on signedness
-            let (half_lhs, half_rhs) = (lhs / 2, rhs / 2);
-            let lsb_masked_bitwise_or = lhs & rhs & 0x1;
-            sum_without_overflow!(half_lhs, half_rhs, lsb_masked_bitwise_or)
+            // SAR or SHR depending on signedness, i.e. the halves are rounded down.
+            // Unlike SAR, division by 2 rounds negative halves towards zero, and
+            // the sum of such halves can be off by one
+            let (half_lhs, half_rhs) = (lhs >> 1, rhs >> 1);
+            let lsb_masked_bitwise_and = lhs & rhs & 0x1;
+            let floor = sum_without_overflow!(half_lhs, half_rhs, lsb_masked_bitwise_and);
+            // The exact midpoint is a half-integer when exactly one of the arguments
+            // is odd, in which case negative floor is one less than the result
+            floor + ((lhs ^ rhs) & 0x1 & (floor < 0) as Self)
         }
     };
 }
@@ -60,4 +77,14 @@ mod tests {
         let result: i32 = (-3).midpoint_via_bitwise_ops(&-2);
         assert_eq!(result, -2);
     }
+
+    #[test]
+    fn midpoint_via_bitwise_ops_is_idempotent_for_negative_odd_args() {
+        // Division of -1 by 2 rounds towards zero, so the halves of negative odd
+        // arguments must be obtained via SAR instead
+        let result: i32 = (-1).midpoint_via_bitwise_ops(&-1);
+        assert_eq!(result, -1);
+        let result: i8 = (-3).midpoint_via_bitwise_ops(&-3);
+        assert_eq!(result, -3);
+    }
 }
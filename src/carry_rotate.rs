@@ -0,0 +1,159 @@
+use crate::RoundingMode;
+
+/// Extension trait providing implementation of midpoint algorithm via addition
+/// followed by [rotation through carry](https://en.wikipedia.org/wiki/Bitwise_operation#Rotate_through_carry),
+/// i.e. `add` and `rcr 1` on `x86_64`. For primitive unsigned integers, the result
+/// is rounded down.
+///
+/// The carry of the addition is the missing top bit of the sum, so rotating it back
+/// into the sum halves the sum without overflow. LLVM doesn't always emit this sequence,
+/// so on `x86_64` it is written via [`core::arch::asm!`]. On other targets, the same
+/// computation is expressed via [`overflowing_add`](u32::overflowing_add).
+///
+/// Since inline assembly can't be evaluated at compile time, the implementations are
+/// not `const` even when the `const_trait_impl` feature is enabled.
+pub trait MidpointViaCarryRotateExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::Down;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
+    /// Returns midpoint using algorithm based on addition followed by rotation through
+    /// carry. For primitive unsigned integers, the result is rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaCarryRotateExt;
+    ///
+    /// let result: u64 = u64::MAX.midpoint_via_carry_rotate(&(u64::MAX - 1));
+    /// assert_eq!(result, u64::MAX - 1);
+    /// ```
+    #[must_use]
+    fn midpoint_via_carry_rotate(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+#[cfg(target_arch = "x86_64")]
+macro_rules! impl_for_single_limb_types {
+    ($($t:ty => $reg_class:ident, $modifier:literal),+) => {
+        $(
+            impl MidpointViaCarryRotateExt for $t {
+                #[inline(always)]
+                fn midpoint_via_carry_rotate(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+                    let (mut lhs, rhs) = (*self, *rhs_ref);
+                    // SAFETY: the instructions only read and write the supplied registers
+                    // and the flags, which are clobbered by default
+                    unsafe {
+                        core::arch::asm!(
+                            concat!("add {lhs", $modifier, "}, {rhs", $modifier, "}"),
+                            concat!("rcr {lhs", $modifier, "}, 1"),
+                            lhs = inout($reg_class) lhs,
+                            rhs = in($reg_class) rhs,
+                            options(pure, nomem, nostack),
+                        );
+                    }
+                    lhs
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+impl_for_single_limb_types!(
+    u8 => reg_byte, "",
+    u16 => reg, ":x",
+    u32 => reg, ":e",
+    u64 => reg, ":r",
+    usize => reg, ":r"
+);
+
+#[cfg(target_arch = "x86_64")]
+impl MidpointViaCarryRotateExt for u128 {
+    #[inline(always)]
+    fn midpoint_via_carry_rotate(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        let (mut lo, mut hi) = (*self as u64, (*self >> 64) as u64);
+        let (rhs_lo, rhs_hi) = (*rhs_ref as u64, (*rhs_ref >> 64) as u64);
+        // SAFETY: the instructions only read and write the supplied registers
+        // and the flags, which are clobbered by default
+        unsafe {
+            // The carry propagates from the low limb to the high limb via `adc` and
+            // then back from the high limb to the low limb via the chained `rcr`
+            core::arch::asm!(
+                "add {lo}, {rhs_lo}",
+                "adc {hi}, {rhs_hi}",
+                "rcr {hi}, 1",
+                "rcr {lo}, 1",
+                lo = inout(reg) lo,
+                hi = inout(reg) hi,
+                rhs_lo = in(reg) rhs_lo,
+                rhs_hi = in(reg) rhs_hi,
+                options(pure, nomem, nostack),
+            );
+        }
+        (hi as u128) << 64 | lo as u128
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+macro_rules! impl_portable_fallback_for_types {
+    ($($t:ty),+) => {
+        $(
+            impl MidpointViaCarryRotateExt for $t {
+                #[inline(always)]
+                fn midpoint_via_carry_rotate(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+                    let (wrapping_sum, carry) = self.overflowing_add(*rhs_ref);
+                    (wrapping_sum >> 1) | ((carry as Self) << (Self::BITS - 1))
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+impl_portable_fallback_for_types!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(all(test, feature = "bitwise"))]
+mod tests {
+    use crate::{MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt};
+
+    #[test]
+    fn midpoint_via_carry_rotate_matches_bitwise_ops_for_all_pairs_of_u8() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                assert_eq!(
+                    a.midpoint_via_carry_rotate(&b),
+                    a.midpoint_via_bitwise_ops(&b)
+                );
+            }
+        }
+    }
+
+    macro_rules! assert_matches_bitwise_ops_near_limits {
+        ($($t:ty),+) => {
+            $(
+                let samples: [$t; 8] = [0, 1, 2, 3, <$t>::MAX / 2, <$t>::MAX / 2 + 1, <$t>::MAX - 1, <$t>::MAX];
+                for a in samples {
+                    for b in samples {
+                        assert_eq!(a.midpoint_via_carry_rotate(&b), a.midpoint_via_bitwise_ops(&b), "{a} {b}");
+                    }
+                }
+            )+
+        };
+    }
+
+    #[test]
+    fn midpoint_via_carry_rotate_matches_bitwise_ops_near_limits() {
+        assert_matches_bitwise_ops_near_limits!(u16, u32, u64, usize, u128);
+    }
+
+    #[test]
+    fn midpoint_via_carry_rotate_propagates_carry_across_limbs_of_u128() {
+        let (a, b) = (u64::MAX as u128, 1);
+        assert_eq!(a.midpoint_via_carry_rotate(&b), 1 << 63);
+        let (a, b) = (u128::MAX, u128::MAX - 2);
+        assert_eq!(a.midpoint_via_carry_rotate(&b), u128::MAX - 1);
+    }
+}
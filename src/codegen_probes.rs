@@ -0,0 +1,98 @@
+//! Exported functions whose generated code is inspected by the `codegen` test.
+//!
+//! The module is compiled only with `--cfg midpoint_codegen_probes` and the functions
+//! have stable symbol names, e.g. `midpoint_probe_midpoint_via_bitwise_ops_u8`, so that
+//! they can be found in the emitted assembly.
+
+use crate::{
+    ConstantTimeMidpointExt, MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt,
+    MidpointViaCarryRotateExt, MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaNaiveMidpointDiffExt, MidpointViaOverflowingAddExt,
+    MidpointViaPrimitivePromotionExt, NaiveMidpointExt,
+};
+
+macro_rules! probes_for {
+    ($mod_name:ident, $t:ty $(, $extra_method:ident)*) => {
+        probes_for!(
+            @module $mod_name,
+            $t,
+            [
+                midpoint_via_bitwise_ops,
+                midpoint_via_cpp_20_std_implementation,
+                midpoint_via_dietz_formula,
+                midpoint_via_overflowing_add,
+                midpoint_via_abs_diff,
+                ct_midpoint_up,
+                ct_midpoint_down,
+                ct_midpoint_toward_zero,
+                ct_midpoint_toward_lhs,
+                ct_midpoint_toward_rhs,
+                ct_midpoint_ties_to_even
+                $(, $extra_method)*
+            ],
+            unsafe [naive_midpoint, midpoint_via_naive_midpoint_diff]
+        );
+    };
+    (
+        @module $mod_name:ident,
+        $t:ty,
+        [$($method:ident),+],
+        unsafe [$($unsafe_method:ident),+]
+    ) => {
+        mod $mod_name {
+            use super::*;
+
+            $(
+                #[export_name = concat!("midpoint_probe_", stringify!($method), "_", stringify!($t))]
+                pub fn $method(lhs: $t, rhs: $t) -> $t {
+                    lhs.$method(&rhs)
+                }
+            )+
+
+            $(
+                /// # Safety
+                ///
+                /// The preconditions of the probed function must hold.
+                #[export_name = concat!("midpoint_probe_", stringify!($unsafe_method), "_", stringify!($t))]
+                pub unsafe fn $unsafe_method(lhs: $t, rhs: $t) -> $t {
+                    unsafe { lhs.$unsafe_method(&rhs) }
+                }
+            )+
+        }
+    };
+}
+
+// MidpointViaPrimitivePromotionExt is not implemented for usize, isize, u128 and i128
+// and MidpointViaCarryRotateExt is implemented only for unsigned integers
+probes_for!(
+    u8_probes,
+    u8,
+    midpoint_via_primitive_promotion,
+    midpoint_via_carry_rotate
+);
+probes_for!(
+    u16_probes,
+    u16,
+    midpoint_via_primitive_promotion,
+    midpoint_via_carry_rotate
+);
+probes_for!(
+    u32_probes,
+    u32,
+    midpoint_via_primitive_promotion,
+    midpoint_via_carry_rotate
+);
+probes_for!(
+    u64_probes,
+    u64,
+    midpoint_via_primitive_promotion,
+    midpoint_via_carry_rotate
+);
+probes_for!(u128_probes, u128, midpoint_via_carry_rotate);
+probes_for!(usize_probes, usize, midpoint_via_carry_rotate);
+probes_for!(i8_probes, i8, midpoint_via_primitive_promotion);
+probes_for!(i16_probes, i16, midpoint_via_primitive_promotion);
+probes_for!(i32_probes, i32, midpoint_via_primitive_promotion);
+probes_for!(i64_probes, i64, midpoint_via_primitive_promotion);
+probes_for!(i128_probes, i128);
+probes_for!(isize_probes, isize);
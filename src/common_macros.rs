@@ -1,4 +1,6 @@
 #[cfg(any(doc, test, doctest, feature = "const_trait_impl"))]
+// Unused when none of the enabled features provides an integer midpoint
+#[allow(unused_macros)]
 macro_rules! provide_trait_impl_for_t {
     ($trait_name:ident, $fn_macro_name:ident, $t:ty) => {
         impl const $trait_name for $t {
@@ -8,6 +10,8 @@ macro_rules! provide_trait_impl_for_t {
 }
 
 #[cfg(not(any(doc, test, doctest, feature = "const_trait_impl")))]
+// Unused when none of the enabled features provides an integer midpoint
+#[allow(unused_macros)]
 macro_rules! provide_trait_impl_for_t {
     ($trait_name:ident, $fn_macro_name:ident, $t:ty) => {
         impl $trait_name for $t {
@@ -17,6 +21,9 @@ macro_rules! provide_trait_impl_for_t {
 }
 
 /// Macro generating a sum of arguments assuming overflow is impossible.
+///
+/// Under `cfg(kani)`, the unchecked sum is used so that the proof harnesses
+/// verify the absence of overflow.
 /// 
 /// When compiled with 
 /// ```ignore
@@ -24,6 +31,7 @@ macro_rules! provide_trait_impl_for_t {
 ///     doc,
 ///     test,
 ///     doctest,
+///     kani,
 ///     all(feature = "unchecked_math", feature = "const_inherent_unchecked_arith")
 /// ))]
 /// ```
@@ -37,6 +45,7 @@ macro_rules! provide_trait_impl_for_t {
 ///     doc,
 ///     test,
 ///     doctest,
+///     kani,
 ///     all(feature = "unchecked_math", feature = "const_inherent_unchecked_arith")
 /// )))]
 /// ```
@@ -48,6 +57,7 @@ macro_rules! provide_trait_impl_for_t {
     doc,
     test,
     doctest,
+    kani,
     all(feature = "unchecked_math", feature = "const_inherent_unchecked_arith")
 ))]
 #[doc(hidden)]
@@ -67,6 +77,7 @@ macro_rules! sum_without_overflow {
     doc,
     test,
     doctest,
+    kani,
     all(feature = "unchecked_math", feature = "const_inherent_unchecked_arith")
 )))]
 #[doc(hidden)]
@@ -80,6 +91,8 @@ macro_rules! sum_without_overflow {
     };
 }
 
+// Unused when none of the enabled features provides an integer midpoint
+#[allow(unused_macros)]
 macro_rules! impl_for_types {
     ($trait_name:ident, $fn_macro_name:ident, [$($t:ty),+]) => {
         $(
@@ -118,6 +131,8 @@ macro_rules! impl_for_prim_ints_with_prim_promotion {
 }
 
 #[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+// Unused when none of the enabled algorithms provides a (safe or unsafe) midpoint function
+#[allow(unused_macros)]
 macro_rules! try_impl_generic_const_fn_for_trait {
     ($trait_name:ident::$fn_name:ident) => {
         #[doc = concat!("Internally calls the midpoint implementation provided by [", stringify!($trait_name), "] trait")]
@@ -131,6 +146,8 @@ macro_rules! try_impl_generic_const_fn_for_trait {
 }
 
 #[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+// Unused when none of the enabled algorithms provides a (safe or unsafe) midpoint function
+#[allow(unused_macros)]
 macro_rules! try_impl_unsafe_generic_const_fn_for_trait {
     ($trait_name:ident::$fn_name:ident) => {
         #[doc = concat!("Internally calls the midpoint implementation provided by [", stringify!($trait_name), "] trait")]
@@ -144,11 +161,15 @@ macro_rules! try_impl_unsafe_generic_const_fn_for_trait {
 }
 
 #[cfg(not(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+// Unused when none of the enabled algorithms provides a (safe or unsafe) midpoint function
+#[allow(unused_macros)]
 macro_rules! try_impl_unsafe_generic_const_fn_for_trait {
     ($trait_name:ident::$fn_name:ident) => {};
 }
 
 #[cfg(not(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+// Unused when none of the enabled algorithms provides a (safe or unsafe) midpoint function
+#[allow(unused_macros)]
 macro_rules! try_impl_generic_const_fn_for_trait {
     ($trait_name:ident::$fn_name:ident) => {};
 }
@@ -0,0 +1,127 @@
+use num_complex::Complex;
+
+#[cfg(feature = "bitwise")]
+use crate::MidpointViaBitwiseOpsExt;
+#[cfg(feature = "cpp_20_std")]
+use crate::MidpointViaCpp20StdImplementationExt;
+#[cfg(feature = "float_rescaling")]
+use crate::MidpointViaFloatRescalingExt;
+use crate::PointMidpointExt;
+
+// Complex numbers are points on the complex plane, so their midpoint is computed
+// component-wise and inherits the guarantees of the midpoint of the components
+
+#[cfg(feature = "bitwise")]
+impl<T: MidpointViaBitwiseOpsExt> MidpointViaBitwiseOpsExt for Complex<T> {
+    /// Returns the component-wise midpoint of two complex numbers computed
+    /// via [`MidpointViaBitwiseOpsExt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    /// use midpoint::MidpointViaBitwiseOpsExt;
+    ///
+    /// let (a, b) = (Complex::new(u16::MAX, 1), Complex::new(u16::MAX - 2, 4));
+    /// assert_eq!(a.midpoint_via_bitwise_ops(&b), Complex::new(u16::MAX - 1, 2));
+    /// ```
+    fn midpoint_via_bitwise_ops(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        self.point_midpoint_with(rhs_ref, T::midpoint_via_bitwise_ops)
+    }
+}
+
+#[cfg(feature = "cpp_20_std")]
+impl<T: MidpointViaCpp20StdImplementationExt> MidpointViaCpp20StdImplementationExt for Complex<T> {
+    /// Returns the component-wise midpoint of two complex numbers computed
+    /// via [`MidpointViaCpp20StdImplementationExt`]. Both components are rounded
+    /// towards the respective components of the first argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    /// use midpoint::MidpointViaCpp20StdImplementationExt;
+    ///
+    /// let (a, b) = (Complex::new(i16::MAX, i16::MIN), Complex::new(i16::MAX - 1, i16::MIN + 1));
+    /// assert_eq!(
+    ///     a.midpoint_via_cpp_20_std_implementation(&b),
+    ///     Complex::new(i16::MAX, i16::MIN)
+    /// );
+    /// ```
+    fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+        self.point_midpoint_with(b_ref, T::midpoint_via_cpp_20_std_implementation)
+    }
+}
+
+#[cfg(feature = "float_rescaling")]
+impl<T: MidpointViaFloatRescalingExt> MidpointViaFloatRescalingExt for Complex<T> {
+    /// Returns the component-wise midpoint of two complex numbers computed
+    /// via [`MidpointViaFloatRescalingExt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    /// use midpoint::MidpointViaFloatRescalingExt;
+    ///
+    /// let (a, b) = (Complex::new(f64::MAX, 1.0), Complex::new(f64::MAX, -1.0));
+    /// assert_eq!(a.midpoint_via_float_rescaling(&b), Complex::new(f64::MAX, 0.0));
+    /// ```
+    fn midpoint_via_float_rescaling(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        self.point_midpoint_with(rhs_ref, T::midpoint_via_float_rescaling)
+    }
+}
+
+impl<T> PointMidpointExt for Complex<T> {
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        Complex::new(
+            midpoint_fn(&self.re, &rhs_ref.re),
+            midpoint_fn(&self.im, &rhs_ref.im),
+        )
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "bitwise",
+    feature = "cpp_20_std",
+    feature = "float_rescaling"
+))]
+mod tests {
+    use crate::{
+        MidpointViaBitwiseOpsExt, MidpointViaCpp20StdImplementationExt,
+        MidpointViaFloatRescalingExt,
+    };
+    use num_complex::Complex;
+
+    #[test]
+    fn midpoint_of_complex_iq_samples_does_not_overflow() {
+        let samples = [
+            Complex::new(i16::MAX, i16::MIN),
+            Complex::new(i16::MAX - 2, i16::MIN + 2),
+        ];
+        assert_eq!(
+            samples[0].midpoint_via_cpp_20_std_implementation(&samples[1]),
+            Complex::new(i16::MAX - 1, i16::MIN + 1)
+        );
+        let (a, b) = (Complex::new(u8::MAX, 0), Complex::new(u8::MAX, 3));
+        assert_eq!(a.midpoint_via_bitwise_ops(&b), Complex::new(u8::MAX, 1));
+    }
+
+    #[test]
+    fn midpoint_of_complex_floats_does_not_overflow() {
+        let (a, b) = (
+            Complex::new(f32::MAX, f32::MIN),
+            Complex::new(f32::MAX, -f32::MAX / 2.0),
+        );
+        assert_eq!(
+            a.midpoint_via_float_rescaling(&b),
+            Complex::new(f32::MAX, f32::MIN * 0.75)
+        );
+    }
+}
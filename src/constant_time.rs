@@ -0,0 +1,300 @@
+use epui::EquisizedPrimitiveUnsignedIntExt as EPUI;
+
+use crate::RoundingMode;
+
+/// Extension trait providing branch-free midpoints of primitive integers for every
+/// [rounding mode](RoundingMode).
+///
+/// Unlike [`MidpointViaCpp20StdImplementationExt`](crate::MidpointViaCpp20StdImplementationExt),
+/// which branches on `a > b`, or [`ExactMidpointExt::midpoint_rounded`](crate::ExactMidpointExt::midpoint_rounded),
+/// the implementations consist only of bitwise operations, shifts, additions and
+/// subtractions. In particular, the comparisons of the arguments are computed
+/// arithmetically, so the control flow and the memory accesses don't depend on the
+/// values of the arguments. This makes the implementations suitable for code handling
+/// secrets, e.g. constant-time binary searches.
+///
+/// The absence of conditional jumps in the generated code is checked by the `codegen`
+/// test for `x86_64`. Note that the compiler doesn't guarantee it for other targets.
+pub trait ConstantTimeMidpointExt {
+    /// Rounding behaviors of the constant-time midpoints. The trait has a method per
+    /// rounding behavior, so it has no single `ROUNDING` constant.
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Returns the midpoint rounded up (towards `MAX`) in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_up(&-2), -2);
+    /// assert_eq!(u8::MAX.ct_midpoint_up(&(u8::MAX - 1)), u8::MAX);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded down (towards `MIN`) in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_down(&-2), -3);
+    /// assert_eq!(u8::MAX.ct_midpoint_down(&(u8::MAX - 1)), u8::MAX - 1);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded towards zero in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_toward_zero(&-2), -2);
+    /// assert_eq!(3i32.ct_midpoint_toward_zero(&2), 2);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_toward_zero(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded towards the first argument (`lhs`) in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_toward_lhs(&-2), -3);
+    /// assert_eq!((-2i32).ct_midpoint_toward_lhs(&-3), -2);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_toward_lhs(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded towards the second argument (`rhs`) in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_toward_rhs(&-2), -2);
+    /// assert_eq!((-2i32).ct_midpoint_toward_rhs(&-3), -3);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_toward_rhs(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded to the nearest integer and, when the exact midpoint
+    /// lies halfway between two integers, to the even one, in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConstantTimeMidpointExt;
+    ///
+    /// assert_eq!(1i32.ct_midpoint_ties_to_even(&2), 2);
+    /// assert_eq!(2i32.ct_midpoint_ties_to_even(&3), 2);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_ties_to_even(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the midpoint rounded according to the supplied [`RoundingMode`]
+    /// in time independent of the arguments.
+    ///
+    /// The rounding mode is assumed to be public: the implementation dispatches on it
+    /// and then calls one of the branch-free methods above.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{ConstantTimeMidpointExt, RoundingMode};
+    ///
+    /// assert_eq!((-3i32).ct_midpoint_rounded(&-2, RoundingMode::TowardZero), -2);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self;
+}
+
+// Returns `(floor, is_half)` where `floor` is the exact midpoint rounded down and
+// `is_half` is `1` when the exact midpoint is a half-integer and `0` otherwise.
+// SAR or SHR rounds the halves down, and the sum of the halves is one less than
+// the floor only when both arguments are odd
+macro_rules! ct_floor_and_is_half {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = ($lhs, $rhs);
+        (
+            sum_without_overflow!(lhs >> 1, rhs >> 1, lhs & rhs & 0x1),
+            (lhs ^ rhs) & 0x1,
+        )
+    }};
+}
+
+// Returns `1` when `$x < $y` and `0` otherwise without comparison instructions.
+// Flipping the sign bit of signed integers maps two's complement to offset binary,
+// which preserves the order (`Self::MIN` is `0` for unsigned integers). Then the borrow
+// of the unsigned subtraction `x - y` is computed as suggested in Hacker's Delight, 2-12
+macro_rules! ct_lt {
+    ($x:expr, $y:expr) => {{
+        let bias = Self::MIN as <Self as EPUI>::EquisizedPrimitiveUnsignedInt;
+        let x = ($x as <Self as EPUI>::EquisizedPrimitiveUnsignedInt) ^ bias;
+        let y = ($y as <Self as EPUI>::EquisizedPrimitiveUnsignedInt) ^ bias;
+        (((!x & y) | ((!x | y) & x.wrapping_sub(y))) >> (Self::BITS - 1)) as Self
+    }};
+}
+
+macro_rules! impl_midpoint_fns_for_t {
+    () => {
+        fn ct_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (floor, is_half) = ct_floor_and_is_half!(*self, *rhs_ref);
+            floor + is_half
+        }
+
+        fn ct_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (floor, _) = ct_floor_and_is_half!(*self, *rhs_ref);
+            floor
+        }
+
+        fn ct_midpoint_toward_zero(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (floor, is_half) = ct_floor_and_is_half!(*self, *rhs_ref);
+            // The sign bit of the floor moved to the lowest bit. `Self::MIN` is `0`
+            // for unsigned integers, whose floor is never negative
+            let is_negative = ((floor & Self::MIN) >> (Self::BITS - 1)) & 0x1;
+            floor + (is_half & is_negative)
+        }
+
+        fn ct_midpoint_toward_lhs(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (lhs, rhs) = (*self, *rhs_ref);
+            let (floor, is_half) = ct_floor_and_is_half!(lhs, rhs);
+            // When the exact midpoint is a half-integer, the arguments differ and
+            // the ceiling is closer to the greater one
+            floor + (is_half & ct_lt!(rhs, lhs))
+        }
+
+        fn ct_midpoint_toward_rhs(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (lhs, rhs) = (*self, *rhs_ref);
+            let (floor, is_half) = ct_floor_and_is_half!(lhs, rhs);
+            floor + (is_half & ct_lt!(lhs, rhs))
+        }
+
+        fn ct_midpoint_ties_to_even(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let (floor, is_half) = ct_floor_and_is_half!(*self, *rhs_ref);
+            floor + (is_half & floor & 0x1)
+        }
+
+        fn ct_midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self {
+            match mode {
+                RoundingMode::Up => self.ct_midpoint_up(rhs_ref),
+                RoundingMode::Down => self.ct_midpoint_down(rhs_ref),
+                RoundingMode::TowardZero => self.ct_midpoint_toward_zero(rhs_ref),
+                RoundingMode::TowardLhs => self.ct_midpoint_toward_lhs(rhs_ref),
+                RoundingMode::TowardRhs => self.ct_midpoint_toward_rhs(rhs_ref),
+                RoundingMode::TiesToEven => self.ct_midpoint_ties_to_even(rhs_ref),
+            }
+        }
+    };
+}
+
+impl_for_all_prim_ints!(
+    trait = ConstantTimeMidpointExt,
+    fn macro = impl_midpoint_fns_for_t
+);
+
+/// Extension trait integrating [`ConstantTimeMidpointExt`] with
+/// [`subtle`](https://docs.rs/subtle), i.e. selecting the rounding via
+/// [`ConditionallySelectable`](subtle::ConditionallySelectable).
+///
+/// It is implemented for every type implementing both traits, i.e. for all primitive
+/// integers except for [`usize`] and [`isize`].
+#[cfg(feature = "subtle")]
+pub trait ConditionallySelectableMidpointExt:
+    ConstantTimeMidpointExt + subtle::ConditionallySelectable
+{
+    /// Returns the midpoint rounded up when `round_up` is set and rounded down
+    /// otherwise, in constant time. This is useful when the rounding itself depends
+    /// on a secret, e.g. in constant-time binary searches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ConditionallySelectableMidpointExt;
+    /// use subtle::Choice;
+    ///
+    /// assert_eq!(2u32.ct_midpoint_conditionally_rounded(&5, Choice::from(1)), 4);
+    /// assert_eq!(2u32.ct_midpoint_conditionally_rounded(&5, Choice::from(0)), 3);
+    /// ```
+    #[must_use]
+    fn ct_midpoint_conditionally_rounded(
+        &self, /*lhs_ref*/
+        rhs_ref: &Self,
+        round_up: subtle::Choice,
+    ) -> Self {
+        Self::conditional_select(
+            &self.ct_midpoint_down(rhs_ref),
+            &self.ct_midpoint_up(rhs_ref),
+            round_up,
+        )
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<T> ConditionallySelectableMidpointExt for T where
+    T: ConstantTimeMidpointExt + subtle::ConditionallySelectable
+{
+}
+
+#[cfg(all(test, feature = "exact"))]
+mod tests {
+    use crate::{ConstantTimeMidpointExt, ExactMidpointExt, RoundingMode};
+
+    #[test]
+    fn ct_midpoint_matches_midpoint_rounded_for_all_pairs_of_8_bit_ints() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                for mode in RoundingMode::ALL {
+                    assert_eq!(
+                        a.ct_midpoint_rounded(&b, mode),
+                        a.midpoint_rounded(&b, mode)
+                    );
+                    let (a, b) = (a as i8, b as i8);
+                    assert_eq!(
+                        a.ct_midpoint_rounded(&b, mode),
+                        a.midpoint_rounded(&b, mode)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ct_midpoint_matches_midpoint_rounded_near_limits_of_128_bit_ints() {
+        let samples = [0, 1, 2, 3, i128::MAX as u128 - 1, i128::MAX as u128];
+        let samples = samples.into_iter().chain(samples.map(|x| !x));
+        for a in samples.clone() {
+            for b in samples.clone() {
+                for mode in RoundingMode::ALL {
+                    assert_eq!(
+                        a.ct_midpoint_rounded(&b, mode),
+                        a.midpoint_rounded(&b, mode)
+                    );
+                    let (a, b) = (a as i128, b as i128);
+                    assert_eq!(
+                        a.ct_midpoint_rounded(&b, mode),
+                        a.midpoint_rounded(&b, mode)
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_midpoint_conditionally_rounded_selects_rounding() {
+        use crate::ConditionallySelectableMidpointExt;
+        use subtle::Choice;
+
+        let (a, b) = (i64::MIN, i64::MAX);
+        assert_eq!(a.ct_midpoint_conditionally_rounded(&b, Choice::from(0)), -1);
+        assert_eq!(a.ct_midpoint_conditionally_rounded(&b, Choice::from(1)), 0);
+    }
+}
@@ -1,5 +1,7 @@
 use epui::EquisizedPrimitiveUnsignedIntExt as EPUI;
 
+use crate::RoundingMode;
+
 try_impl_generic_const_fn_for_trait!(
     MidpointViaCpp20StdImplementationExt::midpoint_via_cpp_20_std_implementation
 );
@@ -8,6 +10,13 @@ try_impl_generic_const_fn_for_trait!(
 /// C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html).
 /// For primitive integers, the result is rounded towards left argument.
 pub trait MidpointViaCpp20StdImplementationExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::TowardLhs;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
     #[must_use]
     fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self;
 }
@@ -43,10 +52,13 @@ macro_rules! impl_midpoint_fn_for_t {
                 a as <Self as EPUI>::EquisizedPrimitiveUnsignedInt,
                 b as <Self as EPUI>::EquisizedPrimitiveUnsignedInt,
             );
+            // Similarly to C++, the difference of the arguments always fits in the
+            // unsigned type but its computation relies on modular arithmetic when
+            // the arguments of a signed type have different signs
             if a > b {
-                a.wrapping_sub(((u_a - u_b) / 2) as Self)
+                a.wrapping_sub((u_a.wrapping_sub(u_b) / 2) as Self)
             } else {
-                a.wrapping_add(((u_b - u_a) / 2) as Self)
+                a.wrapping_add((u_b.wrapping_sub(u_a) / 2) as Self)
             }
         }
     };
@@ -74,4 +86,12 @@ mod tests {
         let result: i32 = (-3).midpoint_via_cpp_20_std_implementation(&-2);
         assert_eq!(result, -3);
     }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_works_for_args_with_diff_signs() {
+        let result = i64::MIN.midpoint_via_cpp_20_std_implementation(&i64::MAX);
+        assert_eq!(result, -1);
+        let result = i64::MAX.midpoint_via_cpp_20_std_implementation(&i64::MIN);
+        assert_eq!(result, 0);
+    }
 }
@@ -0,0 +1,200 @@
+use rust_decimal::Decimal;
+
+use crate::RoundingMode;
+
+/// Extension trait providing midpoint of [decimal numbers](https://docs.rs/rust_decimal)
+/// rounded according to the chosen [`RoundingMode`].
+pub trait DecimalMidpointExt: Sized {
+    /// Returns the midpoint of two decimal numbers rounded according to the supplied
+    /// [`RoundingMode`].
+    ///
+    /// The midpoint is exact whenever it is representable. Otherwise, it is rounded to
+    /// the greatest precision that is representable. Unlike the midpoint of two integers,
+    /// the exact midpoint of two decimal numbers is not necessarily halfway between
+    /// two candidates, so [`RoundingMode::TiesToEven`] rounds to nearest and only resorts
+    /// to the even candidate for ties, i.e. it is the banker's rounding.
+    ///
+    /// Unlike `(a + b) / 2`, the computation never overflows, including at [`Decimal::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use midpoint::{DecimalMidpointExt, RoundingMode};
+    ///
+    /// let (bid, ask) = (Decimal::new(10001, 2), Decimal::new(10002, 2));
+    /// assert_eq!(bid.midpoint_rounded(&ask, RoundingMode::TiesToEven), Decimal::new(100015, 3));
+    /// assert_eq!(
+    ///     Decimal::MAX.midpoint_rounded(&(Decimal::MAX - Decimal::ONE), RoundingMode::TiesToEven),
+    ///     Decimal::MAX - Decimal::ONE
+    /// );
+    /// ```
+    #[must_use]
+    fn midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self;
+}
+
+impl DecimalMidpointExt for Decimal {
+    fn midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self {
+        // 10^37 leaves enough headroom for the sum of the aligned mantissas
+        // multiplied by 5 to fit in i128
+        const ALIGNED_MANTISSA_BOUND: i128 = 10i128.pow(37);
+
+        let (lhs, rhs) = (*self, *rhs_ref);
+        let ((coarse, coarse_scale), (fine, fine_scale)) = {
+            let (l, r) = ((lhs.mantissa(), lhs.scale()), (rhs.mantissa(), rhs.scale()));
+            if l.1 <= r.1 {
+                (l, r)
+            } else {
+                (r, l)
+            }
+        };
+
+        // The mantissas are aligned to the working scale, which is the scale of the finer
+        // argument unless aligning the coarser argument to it would overflow. In the latter
+        // case, the coarser argument is so large compared to the finer one that the digits
+        // of the finer argument below the working scale can only affect the rounding
+        let mut scale = fine_scale;
+        while coarse
+            .checked_mul(10i128.pow(scale - coarse_scale))
+            .is_none_or(|aligned| aligned.abs() >= ALIGNED_MANTISSA_BOUND)
+        {
+            scale -= 1;
+        }
+        let coarse = coarse * 10i128.pow(scale - coarse_scale);
+        let divisor = 10i128.pow(fine_scale - scale);
+        // The discarded digits are accounted for via the sticky flag
+        let (fine, is_sticky) = (fine.div_euclid(divisor), fine.rem_euclid(divisor) != 0);
+
+        let sum = coarse + fine;
+        // Unless is_sticky, the exact midpoint is mantissa / 10^scale. Otherwise, it is
+        // slightly greater but the difference is below the representable precision
+        let (mantissa, scale) = if sum % 2 == 0 && !is_sticky {
+            (sum / 2, scale)
+        } else {
+            (sum * 5, scale + 1)
+        };
+
+        // The rounding is performed once for the greatest precision that is representable
+        let mut dropped_digits = scale.saturating_sub(Decimal::MAX_SCALE);
+        loop {
+            let divisor = 10i128.pow(dropped_digits);
+            let (floor, rem) = (mantissa.div_euclid(divisor), mantissa.rem_euclid(divisor));
+            let is_exact = rem == 0 && !is_sticky;
+            let ceil = floor + !is_exact as i128;
+            let rounded = match mode {
+                RoundingMode::Up => ceil,
+                RoundingMode::Down => floor,
+                RoundingMode::TowardZero if floor < 0 => ceil,
+                RoundingMode::TowardZero => floor,
+                // The midpoint lies between the arguments
+                RoundingMode::TowardLhs if lhs >= rhs => ceil,
+                RoundingMode::TowardLhs => floor,
+                RoundingMode::TowardRhs if rhs >= lhs => ceil,
+                RoundingMode::TowardRhs => floor,
+                RoundingMode::TiesToEven => {
+                    // When no digits are dropped, rem == 0 and the midpoint is exact
+                    let half = divisor / 2;
+                    let is_above_half = rem > half || rem == half && is_sticky;
+                    let is_tie = rem == half && !is_sticky && dropped_digits > 0;
+                    if is_above_half || is_tie && floor % 2 != 0 {
+                        ceil
+                    } else {
+                        floor
+                    }
+                }
+            };
+            // The midpoint never exceeds the arguments in magnitude, so the rounding
+            // to an integer always succeeds
+            if let Ok(result) = Decimal::try_from_i128_with_scale(rounded, scale - dropped_digits) {
+                return result;
+            }
+            dropped_digits += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecimalMidpointExt, RoundingMode};
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn midpoint_rounded_does_not_overflow() {
+        let mode = RoundingMode::TiesToEven;
+        assert_eq!(
+            Decimal::MAX.midpoint_rounded(&Decimal::MAX, mode),
+            Decimal::MAX
+        );
+        assert_eq!(
+            Decimal::MIN.midpoint_rounded(&Decimal::MAX, mode),
+            Decimal::ZERO
+        );
+        let result = Decimal::MAX.midpoint_rounded(&(Decimal::MAX - Decimal::TWO), mode);
+        assert_eq!(result, Decimal::MAX - Decimal::ONE);
+        // The exact midpoint ends with .5, which doesn't fit in 96 bits
+        let result = Decimal::MAX.midpoint_rounded(&(Decimal::MAX - Decimal::ONE), mode);
+        assert_eq!(result, Decimal::MAX - Decimal::ONE);
+        let result =
+            Decimal::MAX.midpoint_rounded(&(Decimal::MAX - Decimal::ONE), RoundingMode::Up);
+        assert_eq!(result, Decimal::MAX);
+    }
+
+    #[test]
+    fn midpoint_rounded_is_exact_when_representable() {
+        let mode = RoundingMode::Down;
+        let (a, b) = (Decimal::new(1, 0), Decimal::new(2, 0));
+        assert_eq!(a.midpoint_rounded(&b, mode), Decimal::new(15, 1));
+        let (a, b) = (Decimal::new(-1, 28), Decimal::new(3, 28));
+        assert_eq!(a.midpoint_rounded(&b, mode), Decimal::new(1, 28));
+        let (a, b) = (Decimal::new(1, 0), Decimal::new(2, 28));
+        assert_eq!(
+            a.midpoint_rounded(&b, mode),
+            Decimal::from_i128_with_scale(5_000_000_000_000_000_000_000_000_001, 28)
+        );
+    }
+
+    #[test]
+    fn midpoint_rounded_supports_all_rounding_modes() {
+        // The exact midpoint is -0.00000000000000000000000000005
+        let (a, b) = (Decimal::new(-1, 28), Decimal::new(0, 0));
+        let cases = [
+            (RoundingMode::Up, Decimal::new(0, 28)),
+            (RoundingMode::Down, Decimal::new(-1, 28)),
+            (RoundingMode::TowardZero, Decimal::new(0, 28)),
+            (RoundingMode::TowardLhs, Decimal::new(-1, 28)),
+            (RoundingMode::TowardRhs, Decimal::new(0, 28)),
+            (RoundingMode::TiesToEven, Decimal::new(0, 28)),
+        ];
+        for (mode, expected) in cases {
+            assert_eq!(a.midpoint_rounded(&b, mode), expected, "{mode:?}");
+        }
+        // The exact midpoint is -0.00000000000000000000000000015
+        let (a, b) = (Decimal::new(-3, 28), Decimal::new(0, 0));
+        assert_eq!(
+            a.midpoint_rounded(&b, RoundingMode::TiesToEven),
+            Decimal::new(-2, 28)
+        );
+    }
+
+    #[test]
+    fn midpoint_rounded_accounts_for_discarded_digits() {
+        // The exact midpoint is 39614081257132168796771975167.5 + 0.5 * 10^-28,
+        // which is above the tie
+        let tiny = Decimal::new(1, 28);
+        let result = Decimal::MAX.midpoint_rounded(&tiny, RoundingMode::TiesToEven);
+        assert_eq!(
+            result,
+            Decimal::from_i128_with_scale(39614081257132168796771975168, 0)
+        );
+        let result = Decimal::MAX.midpoint_rounded(&-tiny, RoundingMode::TiesToEven);
+        assert_eq!(
+            result,
+            Decimal::from_i128_with_scale(39614081257132168796771975167, 0)
+        );
+        let result = Decimal::MAX.midpoint_rounded(&Decimal::ZERO, RoundingMode::TiesToEven);
+        assert_eq!(
+            result,
+            Decimal::from_i128_with_scale(39614081257132168796771975168, 0)
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::RoundingMode;
+
+try_impl_generic_const_fn_for_trait!(MidpointViaDietzFormulaExt::midpoint_via_dietz_formula);
+
+/// Extension trait providing implementation of midpoint algorithm via
+/// [Dietz's formula](https://aggregate.org/MAGIC/#Average%20of%20Integers)
+/// `(a & b) + ((a ^ b) >> 1)`. For primitive integers, the result is rounded down.
+pub trait MidpointViaDietzFormulaExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::Down;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
+    /// Returns midpoint using
+    /// [Dietz's formula](https://aggregate.org/MAGIC/#Average%20of%20Integers)
+    /// `(a & b) + ((a ^ b) >> 1)`. For primitive integers, the result is rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaDietzFormulaExt;
+    ///
+    /// let result: i32 = (-3).midpoint_via_dietz_formula(&-2);
+    /// assert_eq!(result, -3);
+    /// ```
+    #[must_use]
+    fn midpoint_via_dietz_formula(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+macro_rules! impl_midpoint_fn_for_t {
+    () => {
+        fn midpoint_via_dietz_formula(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // At the time of writing, explicit dereferencing is necessary because
+            // `<&u8 as Add<&u8>>::add` is not yet stable as a const fn
+            // and requires `#![feature(const_ops)]`
+            //
+            // Rust unstable book entry:
+            // https://doc.rust-lang.org/beta/unstable-book/library-features/const-ops.html
+            let (lhs, rhs) = (*self, *rhs_ref);
+            // a + b == 2 * (a & b) + (a ^ b), i.e. the bits common to both arguments
+            // are halved by keeping them as is and the differing bits are halved by
+            // the shift. SAR or SHR depending on signedness rounds the half down
+            sum_without_overflow!(lhs & rhs, (lhs ^ rhs) >> 1)
+        }
+    };
+}
+
+impl_for_all_prim_ints!(
+    trait = MidpointViaDietzFormulaExt,
+    fn macro = impl_midpoint_fn_for_t
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaDietzFormulaExt;
+
+    #[test]
+    fn midpoint_via_dietz_formula_rounds_down_including_when_args_are_positive() {
+        let result: i32 = 2.midpoint_via_dietz_formula(&3);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn midpoint_via_dietz_formula_rounds_down_including_when_args_are_negative() {
+        let result: i32 = (-3).midpoint_via_dietz_formula(&-2);
+        assert_eq!(result, -3);
+    }
+
+    #[test]
+    fn midpoint_via_dietz_formula_doesnt_overflow() {
+        assert_eq!(u64::MAX.midpoint_via_dietz_formula(&u64::MAX), u64::MAX);
+        assert_eq!(i64::MIN.midpoint_via_dietz_formula(&i64::MAX), -1);
+    }
+}
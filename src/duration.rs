@@ -0,0 +1,106 @@
+use core::time::Duration;
+
+#[cfg(feature = "bitwise")]
+use crate::MidpointViaBitwiseOpsExt;
+#[cfg(feature = "cpp_20_std")]
+use crate::MidpointViaCpp20StdImplementationExt;
+
+#[cfg(feature = "bitwise")]
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+#[cfg(feature = "bitwise")]
+impl MidpointViaBitwiseOpsExt for Duration {
+    /// Returns midpoint of two [`Duration`]s using algorithm using
+    /// [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation)
+    /// on their total number of nanoseconds. The result is rounded down
+    /// to the nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use midpoint::MidpointViaBitwiseOpsExt;
+    ///
+    /// let result = Duration::MAX.midpoint_via_bitwise_ops(&Duration::MAX);
+    /// assert_eq!(result, Duration::MAX);
+    /// ```
+    fn midpoint_via_bitwise_ops(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        // u64::MAX seconds with 999_999_999 nanoseconds take less than 94 bits
+        let nanos = self
+            .as_nanos()
+            .midpoint_via_bitwise_ops(&rhs_ref.as_nanos());
+        // The midpoint never exceeds the greater of the arguments,
+        // therefore the number of seconds fits in u64
+        Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+}
+
+#[cfg(feature = "cpp_20_std")]
+impl MidpointViaCpp20StdImplementationExt for Duration {
+    /// Returns midpoint of two [`Duration`]s using algorithm
+    /// [as suggested for  C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html).
+    /// The result is rounded towards left argument to the nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use midpoint::MidpointViaCpp20StdImplementationExt;
+    ///
+    /// let (a, b) = (Duration::from_nanos(3), Duration::from_nanos(2));
+    /// assert_eq!(a.midpoint_via_cpp_20_std_implementation(&b), a);
+    /// ```
+    fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+        let (a, b) = (*self, *b_ref);
+        // Division of a Duration by an integer truncates the nanoseconds,
+        // which mirrors the division of the unsigned difference
+        if a > b {
+            a - (a - b) / 2
+        } else {
+            a + (b - a) / 2
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bitwise", feature = "cpp_20_std"))]
+mod tests {
+    use crate::{MidpointViaBitwiseOpsExt, MidpointViaCpp20StdImplementationExt};
+    use core::time::Duration;
+
+    #[test]
+    fn midpoint_via_bitwise_ops_for_duration_does_not_overflow_at_max() {
+        let result = Duration::MAX.midpoint_via_bitwise_ops(&Duration::MAX);
+        assert_eq!(result, Duration::MAX);
+        let result = Duration::ZERO.midpoint_via_bitwise_ops(&Duration::MAX);
+        assert_eq!(result, Duration::new(u64::MAX / 2, 999_999_999));
+    }
+
+    #[test]
+    fn midpoint_via_bitwise_ops_for_duration_is_nanosecond_exact() {
+        let (a, b) = (Duration::new(1, 999_999_999), Duration::new(4, 0));
+        let result = a.midpoint_via_bitwise_ops(&b);
+        assert_eq!(result, Duration::new(2, 999_999_999));
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_duration_rounds_towards_left_arg() {
+        let (a, b) = (Duration::new(1, 999_999_999), Duration::new(4, 0));
+        assert_eq!(
+            a.midpoint_via_cpp_20_std_implementation(&b),
+            Duration::new(2, 999_999_999)
+        );
+        assert_eq!(
+            b.midpoint_via_cpp_20_std_implementation(&a),
+            Duration::new(3, 0)
+        );
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_duration_does_not_overflow_at_max() {
+        let result = Duration::MAX.midpoint_via_cpp_20_std_implementation(&Duration::ZERO);
+        assert_eq!(result, Duration::new(u64::MAX / 2 + 1, 0));
+    }
+}
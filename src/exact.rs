@@ -0,0 +1,194 @@
+use crate::RoundingMode;
+
+/// Exact midpoint of two primitive integers, i.e. `floor + 0.5` if [`HalfInt::is_half`]
+/// and `floor` otherwise.
+///
+/// All rounding modes described by [`RoundingMode`] can be derived from it deterministically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HalfInt<T> {
+    floor: T,
+    is_half: bool,
+}
+
+impl<T: Copy> HalfInt<T> {
+    /// Returns the greatest integer that is less than or equal to the exact midpoint.
+    #[must_use]
+    pub const fn floor(self) -> T {
+        self.floor
+    }
+
+    /// Returns `true` if the exact midpoint is not an integer but lies halfway between
+    /// two consecutive integers.
+    #[must_use]
+    pub const fn is_half(self) -> bool {
+        self.is_half
+    }
+}
+
+/// Extension trait providing the exact midpoint of primitive integers, which is computed
+/// without overflow via [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation),
+/// and the midpoint rounded according to the chosen [`RoundingMode`].
+pub trait ExactMidpointExt: Sized {
+    /// Rounding behaviors supported by [`midpoint_rounded`](ExactMidpointExt::midpoint_rounded).
+    /// The rounding is chosen per call, so the trait has no single `ROUNDING` constant.
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Returns the exact midpoint, which is either an integer or a half-integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::ExactMidpointExt;
+    ///
+    /// let result = (-3i32).exact_midpoint(&0);
+    /// assert_eq!((result.floor(), result.is_half()), (-2, true));
+    /// ```
+    #[must_use]
+    fn exact_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> HalfInt<Self>;
+
+    /// Returns the midpoint rounded according to the supplied [`RoundingMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{ExactMidpointExt, RoundingMode};
+    ///
+    /// assert_eq!((-3i32).midpoint_rounded(&0, RoundingMode::TowardZero), -1);
+    /// assert_eq!((-3i32).midpoint_rounded(&0, RoundingMode::TowardLhs), -2);
+    /// assert_eq!((-3i32).midpoint_rounded(&0, RoundingMode::TiesToEven), -2);
+    /// ```
+    #[must_use]
+    fn midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self;
+}
+
+macro_rules! impl_rounding_for_half_int {
+    ($($t:ty),+) => {
+        $(
+            impl HalfInt<$t> {
+                /// Returns the least integer that is greater than or equal to the exact midpoint.
+                #[must_use]
+                pub const fn ceil(self) -> $t {
+                    // The exact midpoint never exceeds the greater argument,
+                    // so its ceiling cannot overflow
+                    self.floor + self.is_half as $t
+                }
+
+                /// Returns the exact midpoint rounded towards zero.
+                #[must_use]
+                // Type limit comparisons are deemed useless for unsigned types
+                // by the compiler but they are necessary for signed types
+                #[allow(unused_comparisons)]
+                pub const fn round_toward_zero(self) -> $t {
+                    self.floor + (self.is_half && self.floor < 0) as $t
+                }
+
+                /// Returns the exact midpoint rounded towards the even integer
+                /// when it's a half-integer.
+                #[must_use]
+                pub const fn round_ties_to_even(self) -> $t {
+                    self.floor + (self.is_half && self.floor & 1 == 1) as $t
+                }
+
+                /// Returns the exact midpoint rounded towards `target`.
+                #[must_use]
+                pub const fn round_toward(self, target: $t) -> $t {
+                    self.floor + (self.is_half && target > self.floor) as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_rounding_for_half_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_midpoint_fn_for_t {
+    () => {
+        fn exact_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> HalfInt<Self> {
+            // At the time of writing, explicit dereferencing is necessary because
+            // `<&u8 as Add<&u8>>::add` is not yet stable as a const fn
+            // and requires `#![feature(const_ops)]`
+            //
+            // Rust unstable book entry:
+            // https://doc.rust-lang.org/beta/unstable-book/library-features/const-ops.html
+            let (lhs, rhs) = (*self, *rhs_ref);
+            // >> is SAR or SHR depending on signedness, i.e. it rounds down
+            let (half_lhs, half_rhs) = (lhs >> 1, rhs >> 1);
+            let lsb_masked_bitwise_and = lhs & rhs & 0x1;
+            HalfInt {
+                floor: sum_without_overflow!(half_lhs, half_rhs, lsb_masked_bitwise_and),
+                is_half: (lhs ^ rhs) & 0x1 == 1,
+            }
+        }
+
+        fn midpoint_rounded(&self /*lhs_ref*/, rhs_ref: &Self, mode: RoundingMode) -> Self {
+            let exact = self.exact_midpoint(rhs_ref);
+            match mode {
+                RoundingMode::Up => exact.ceil(),
+                RoundingMode::Down => exact.floor(),
+                RoundingMode::TowardZero => exact.round_toward_zero(),
+                RoundingMode::TowardLhs => exact.round_toward(*self),
+                RoundingMode::TowardRhs => exact.round_toward(*rhs_ref),
+                RoundingMode::TiesToEven => exact.round_ties_to_even(),
+            }
+        }
+    };
+}
+
+impl_for_all_prim_ints!(
+    trait = ExactMidpointExt,
+    fn macro = impl_midpoint_fn_for_t
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{ExactMidpointExt, RoundingMode};
+
+    #[test]
+    fn exact_midpoint_does_not_overflow() {
+        let result = i8::MIN.exact_midpoint(&i8::MAX);
+        assert_eq!((result.floor(), result.is_half()), (-1, true));
+        let result = u128::MAX.exact_midpoint(&u128::MAX);
+        assert_eq!((result.floor(), result.is_half()), (u128::MAX, false));
+        assert_eq!(result.ceil(), u128::MAX);
+        let result = i64::MIN.exact_midpoint(&i64::MIN);
+        assert_eq!((result.floor(), result.is_half()), (i64::MIN, false));
+    }
+
+    #[test]
+    fn midpoint_rounded_supports_all_rounding_modes() {
+        let (a, b) = (-3i16, 2i16);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::Up), 0);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::Down), -1);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::TowardZero), 0);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::TowardLhs), -1);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::TowardRhs), 0);
+        assert_eq!(a.midpoint_rounded(&b, RoundingMode::TiesToEven), 0);
+        assert_eq!((-5i16).midpoint_rounded(&-2, RoundingMode::TiesToEven), -4);
+        assert_eq!((-5i16).midpoint_rounded(&-2, RoundingMode::TowardZero), -3);
+    }
+
+    #[test]
+    fn midpoint_rounded_matches_reference_for_all_pairs_of_i8() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let sum = a as i16 + b as i16;
+                let floor = sum.div_euclid(2);
+                let ceil = floor + sum.rem_euclid(2);
+                let toward_zero = sum / 2;
+                let toward = |target: i8| if (target as i16) <= floor { floor } else { ceil };
+                let even = if floor % 2 == 0 { floor } else { ceil };
+                let expected = [
+                    (RoundingMode::Up, ceil),
+                    (RoundingMode::Down, floor),
+                    (RoundingMode::TowardZero, toward_zero),
+                    (RoundingMode::TowardLhs, toward(a)),
+                    (RoundingMode::TowardRhs, toward(b)),
+                    (RoundingMode::TiesToEven, even),
+                ];
+                for (mode, expected) in expected {
+                    assert_eq!(a.midpoint_rounded(&b, mode) as i16, expected);
+                }
+            }
+        }
+    }
+}
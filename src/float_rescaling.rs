@@ -0,0 +1,110 @@
+use crate::RoundingMode;
+
+try_impl_generic_const_fn_for_trait!(MidpointViaFloatRescalingExt::midpoint_via_float_rescaling);
+
+/// Extension trait providing implementation of midpoint algorithm for
+/// [primitive IEEE 754 floating point numbers](https://en.wikipedia.org/wiki/IEEE_754)
+/// via rescaling of the arguments whenever their sum may overflow.
+/// For primitive floats, the result is rounded to nearest, ties to even.
+pub trait MidpointViaFloatRescalingExt {
+    /// Rounding behavior of the algorithm for primitive floats, i.e. to nearest, ties to even.
+    const ROUNDING: RoundingMode = RoundingMode::TiesToEven;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
+    /// Returns midpoint using algorithm that halves the arguments before summing them
+    /// whenever their sum may overflow. For primitive floats, the result is rounded to
+    /// nearest, ties to even.
+    ///
+    /// When either argument is NaN or the arguments are infinities of opposite signs,
+    /// the result is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaFloatRescalingExt;
+    ///
+    /// let result: f64 = f64::MAX.midpoint_via_float_rescaling(&f64::MAX);
+    /// assert_eq!(result, f64::MAX);
+    /// ```
+    #[must_use]
+    fn midpoint_via_float_rescaling(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+macro_rules! impl_midpoint_fn_for_t {
+    () => {
+        fn midpoint_via_float_rescaling(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // Associated constants can't be used in items nested in the
+            // function due to error[E0401]:
+            //  "can't use `Self` from outer item"
+            //
+            // Halving of the values below lo would lose precision
+            let lo = Self::MIN_POSITIVE * 2.0;
+            // Summation of the values above hi may overflow
+            let hi = Self::MAX / 2.0;
+
+            let (lhs, rhs) = (*self, *rhs_ref);
+            let (abs_lhs, abs_rhs) = (lhs.abs(), rhs.abs());
+            if abs_lhs <= hi && abs_rhs <= hi {
+                // At most one of the operations rounds: division by 2 is exact
+                // unless the sum is tiny, in which case the sum itself is exact
+                (lhs + rhs) / 2.0
+            } else if abs_lhs < lo {
+                // abs_rhs > hi, so whether lhs is halved or not makes no difference
+                // after rounding
+                lhs + rhs / 2.0
+            } else if abs_rhs < lo {
+                lhs / 2.0 + rhs
+            } else {
+                lhs / 2.0 + rhs / 2.0
+            }
+        }
+    };
+}
+
+impl_for_types!(
+    MidpointViaFloatRescalingExt,
+    impl_midpoint_fn_for_t,
+    [f32, f64]
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaFloatRescalingExt;
+
+    #[test]
+    fn midpoint_via_float_rescaling_does_not_overflow() {
+        assert_eq!(f64::MAX.midpoint_via_float_rescaling(&f64::MAX), f64::MAX);
+        assert_eq!(f32::MIN.midpoint_via_float_rescaling(&f32::MAX), 0.0);
+        assert_eq!(
+            f64::MAX.midpoint_via_float_rescaling(&(f64::MAX / 2.0)),
+            f64::MAX * 0.75
+        );
+    }
+
+    #[test]
+    fn midpoint_via_float_rescaling_does_not_lose_precision_for_subnormals() {
+        let min_subnormal = f64::from_bits(1);
+        assert_eq!(
+            min_subnormal.midpoint_via_float_rescaling(&(min_subnormal * 3.0)),
+            min_subnormal * 2.0
+        );
+        assert_eq!(
+            f64::MAX.midpoint_via_float_rescaling(&min_subnormal),
+            f64::MAX / 2.0
+        );
+    }
+
+    #[test]
+    fn midpoint_via_float_rescaling_returns_nan_for_opposite_infinities() {
+        assert!(f32::INFINITY
+            .midpoint_via_float_rescaling(&f32::NEG_INFINITY)
+            .is_nan());
+        assert_eq!(
+            f32::INFINITY.midpoint_via_float_rescaling(&1.0),
+            f32::INFINITY
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use half::{bf16, f16};
+
+use crate::MidpointViaFloatRescalingExt;
+
+// Widening to f32 doesn't make the sum of the arguments exact. However, f32 midpoint is
+// correctly rounded and f32 has more than twice as many significand bits as both f16 and
+// bf16 (24 >= 2 * 11 + 2 and 24 >= 2 * 8 + 2). Therefore, according to Figueroa's theorem,
+// rounding of the f32 midpoint to the narrower type produces the correctly rounded midpoint
+// as if it had been computed directly.
+//
+// Samuel A. Figueroa. "When is double rounding innocuous?"
+// https://dl.acm.org/doi/10.1145/221332.221334
+//
+// Widening to f64 would make the sum exact for f16 but, at the time of writing, the software
+// fallback of `f16::from_f64` discards the lower bits of the f64 significand before rounding
+// and, therefore, doesn't always round correctly.
+
+macro_rules! impl_for_half_precision_floats {
+    ($($t:ty),+) => {
+        $(
+            impl MidpointViaFloatRescalingExt for $t {
+                #[doc = concat!("Returns midpoint of two [`", stringify!($t), "`]s computed via")]
+                /// [`f32`]. The result is rounded to nearest, ties to even.
+                ///
+                /// When either argument is NaN or the arguments are infinities of opposite signs,
+                /// the result is NaN.
+                fn midpoint_via_float_rescaling(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+                    let (lhs, rhs) = (self.to_f32(), rhs_ref.to_f32());
+                    <$t>::from_f32(lhs.midpoint_via_float_rescaling(&rhs))
+                }
+            }
+        )+
+    };
+}
+
+impl_for_half_precision_floats!(f16, bf16);
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaFloatRescalingExt;
+    use half::{bf16, f16};
+
+    #[test]
+    fn midpoint_via_float_rescaling_for_f16_does_not_overflow() {
+        assert_eq!(f16::MAX.midpoint_via_float_rescaling(&f16::MAX), f16::MAX);
+        assert_eq!(f16::MIN.midpoint_via_float_rescaling(&f16::MAX), f16::ZERO);
+        let min_subnormal = f16::from_bits(1);
+        let result = min_subnormal.midpoint_via_float_rescaling(&f16::from_bits(3));
+        assert_eq!(result, f16::from_bits(2));
+    }
+
+    #[test]
+    fn midpoint_via_float_rescaling_for_bf16_does_not_overflow() {
+        assert_eq!(
+            bf16::MAX.midpoint_via_float_rescaling(&bf16::MAX),
+            bf16::MAX
+        );
+        assert_eq!(
+            bf16::MIN.midpoint_via_float_rescaling(&bf16::MAX),
+            bf16::ZERO
+        );
+        assert!(bf16::INFINITY
+            .midpoint_via_float_rescaling(&bf16::NEG_INFINITY)
+            .is_nan());
+    }
+
+    #[test]
+    fn midpoint_via_float_rescaling_for_f16_is_correctly_rounded() {
+        // Adjacent finite f16 in ascending order
+        let neighbors = |x: f16| {
+            let bits = x.to_bits();
+            let (down, up) = match (x.is_sign_negative(), bits & 0x7FFF) {
+                (_, 0) => (0x8001, 0x0001),
+                (false, _) => (bits - 1, bits + 1),
+                (true, _) => (bits + 1, bits - 1),
+            };
+            (f16::from_bits(down), f16::from_bits(up))
+        };
+        let finite = |step| {
+            (0..=u16::MAX)
+                .step_by(step)
+                .map(f16::from_bits)
+                .filter(|x| x.is_finite())
+        };
+        for a in finite(61) {
+            for b in finite(67) {
+                let result = a.midpoint_via_float_rescaling(&b);
+                // The midpoints of all pairs of f16 are exactly representable in f64
+                let exact = (a.to_f64() + b.to_f64()) / 2.0;
+                let error = (result.to_f64() - exact).abs();
+                let (down, up) = neighbors(result);
+                for neighbor in [down, up].into_iter().filter(|x| x.is_finite()) {
+                    let neighbor_error = (neighbor.to_f64() - exact).abs();
+                    assert!(error <= neighbor_error, "{a} {b} {result}");
+                    if error == neighbor_error {
+                        assert_eq!(result.to_bits() & 1, 0, "{a} {b} {result}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn midpoint_via_float_rescaling_for_bf16_ties_to_even() {
+        let one = bf16::ONE;
+        let next = bf16::from_bits(one.to_bits() + 1);
+        let next_next = bf16::from_bits(one.to_bits() + 2);
+        // The exact midpoint of 1 and its successor is the tie between them
+        assert_eq!(one.midpoint_via_float_rescaling(&next), one);
+        assert_eq!(next.midpoint_via_float_rescaling(&next_next), next_next);
+    }
+}
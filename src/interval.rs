@@ -0,0 +1,422 @@
+#[cfg(any(feature = "cpp_20_std", feature = "float_rescaling"))]
+use core::ops::{Range, RangeInclusive};
+
+#[cfg(feature = "cpp_20_std")]
+use epui::EquisizedPrimitiveUnsignedIntExt as EPUI;
+
+#[cfg(feature = "cpp_20_std")]
+use crate::MidpointViaCpp20StdImplementationExt;
+#[cfg(feature = "float_rescaling")]
+use crate::MidpointViaFloatRescalingExt;
+
+/// Closed interval `[lo, hi]` with `lo <= hi`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Copy + PartialOrd> Interval<T> {
+    /// Returns the interval `[lo, hi]` or [`None`] unless `lo <= hi`, which
+    /// is also the case when either bound is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::Interval;
+    ///
+    /// assert!(Interval::new(1, 2).is_some());
+    /// assert!(Interval::new(2, 1).is_none());
+    /// assert!(Interval::new(0.0, f64::NAN).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(lo: T, hi: T) -> Option<Self> {
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the lower bound of the interval.
+    #[must_use]
+    pub const fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// Returns the upper bound of the interval.
+    #[must_use]
+    pub const fn hi(&self) -> T {
+        self.hi
+    }
+}
+
+/// Extension trait providing the center (i.e. the midpoint of the bounds) of intervals
+/// and ranges as well as the related operations. The center is computed without overflow
+/// even when the distance between the bounds doesn't fit in the type of the bounds.
+///
+/// For primitive integers, the center is rounded towards the start of the range.
+/// For primitive floats, the center is rounded to nearest, ties to even.
+pub trait IntervalMidpointExt: Sized {
+    /// Type of the bounds.
+    type Bound;
+    /// Type of the half of the distance between the bounds.
+    type HalfWidth;
+
+    /// Returns the midpoint of the bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::IntervalMidpointExt;
+    ///
+    /// assert_eq!((i64::MIN..=i64::MAX).center(), -1);
+    /// assert_eq!((f64::MIN..f64::MAX).center(), 0.0);
+    /// ```
+    #[must_use]
+    fn center(&self) -> Self::Bound;
+
+    /// Returns the half of the distance between the bounds. For primitive integers,
+    /// the result is of the equisized unsigned type and is rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::IntervalMidpointExt;
+    ///
+    /// assert_eq!((i64::MIN..=i64::MAX).half_width(), u64::MAX / 2);
+    /// assert_eq!((-1.0..2.0).half_width(), 1.5);
+    /// ```
+    #[must_use]
+    fn half_width(&self) -> Self::HalfWidth;
+
+    /// Splits at the center into two adjacent halves.
+    ///
+    /// For [`Range`], [`Interval`], and [`RangeInclusive`] of primitive floats, the center
+    /// belongs to both halves (as the end of the first half and the start of the second half).
+    /// For [`RangeInclusive`] of primitive integers, the halves are disjoint and the center
+    /// belongs to the first half, which is empty only when the range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::IntervalMidpointExt;
+    ///
+    /// assert_eq!((0..10).split_at_mid(), (0..5, 5..10));
+    /// assert_eq!((0..=10).split_at_mid(), (0..=5, 6..=10));
+    /// assert_eq!((0.0..=10.0).split_at_mid(), (0.0..=5.0, 5.0..=10.0));
+    /// ```
+    #[must_use]
+    fn split_at_mid(&self) -> (Self, Self);
+}
+
+#[cfg(feature = "cpp_20_std")]
+macro_rules! impl_for_prim_ints {
+    ($($t:ty),+) => {
+        $(
+            impl IntervalMidpointExt for Range<$t> {
+                type Bound = $t;
+                type HalfWidth = <$t as EPUI>::EquisizedPrimitiveUnsignedInt;
+
+                fn center(&self) -> $t {
+                    self.start.midpoint_via_cpp_20_std_implementation(&self.end)
+                }
+
+                fn half_width(&self) -> Self::HalfWidth {
+                    self.start.abs_diff(self.end) / 2
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    let mid = self.center();
+                    (self.start..mid, mid..self.end)
+                }
+            }
+
+            impl IntervalMidpointExt for RangeInclusive<$t> {
+                type Bound = $t;
+                type HalfWidth = <$t as EPUI>::EquisizedPrimitiveUnsignedInt;
+
+                fn center(&self) -> $t {
+                    self.start().midpoint_via_cpp_20_std_implementation(self.end())
+                }
+
+                fn half_width(&self) -> Self::HalfWidth {
+                    self.start().abs_diff(*self.end()) / 2
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    if self.is_empty() {
+                        return (self.clone(), self.clone());
+                    }
+                    let (start, end) = (*self.start(), *self.end());
+                    let mid = self.center();
+                    if mid < end {
+                        (start..=mid, mid + 1..=end)
+                    } else {
+                        // The range contains the single element. Since mid + 1 may overflow,
+                        // the empty second half is obtained by exhausting the range.
+                        let mut empty = self.clone();
+                        empty.next();
+                        (self.clone(), empty)
+                    }
+                }
+            }
+
+            impl IntervalMidpointExt for Interval<$t> {
+                type Bound = $t;
+                type HalfWidth = <$t as EPUI>::EquisizedPrimitiveUnsignedInt;
+
+                fn center(&self) -> $t {
+                    self.lo.midpoint_via_cpp_20_std_implementation(&self.hi)
+                }
+
+                fn half_width(&self) -> Self::HalfWidth {
+                    self.lo.abs_diff(self.hi) / 2
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    let mid = self.center();
+                    (Self { lo: self.lo, hi: mid }, Self { lo: mid, hi: self.hi })
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "cpp_20_std")]
+impl_for_prim_ints!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Halving the distance between the bounds rounds only once. When the distance overflows,
+// the bounds are too large for their halves to round, so they are halved first instead
+#[cfg(feature = "float_rescaling")]
+macro_rules! float_half_width {
+    ($start:expr, $end:expr) => {{
+        let (start, end) = ($start, $end);
+        let distance = end - start;
+        if distance.is_finite() {
+            distance / 2.0
+        } else {
+            end / 2.0 - start / 2.0
+        }
+    }};
+}
+
+#[cfg(feature = "float_rescaling")]
+macro_rules! impl_for_prim_floats {
+    ($($t:ty),+) => {
+        $(
+            impl IntervalMidpointExt for Range<$t> {
+                type Bound = $t;
+                type HalfWidth = $t;
+
+                fn center(&self) -> $t {
+                    self.start.midpoint_via_float_rescaling(&self.end)
+                }
+
+                fn half_width(&self) -> $t {
+                    float_half_width!(self.start, self.end)
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    let mid = self.center();
+                    (self.start..mid, mid..self.end)
+                }
+            }
+
+            impl IntervalMidpointExt for RangeInclusive<$t> {
+                type Bound = $t;
+                type HalfWidth = $t;
+
+                fn center(&self) -> $t {
+                    self.start().midpoint_via_float_rescaling(self.end())
+                }
+
+                fn half_width(&self) -> $t {
+                    float_half_width!(*self.start(), *self.end())
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    let mid = self.center();
+                    (*self.start()..=mid, mid..=*self.end())
+                }
+            }
+
+            impl IntervalMidpointExt for Interval<$t> {
+                type Bound = $t;
+                type HalfWidth = $t;
+
+                fn center(&self) -> $t {
+                    self.lo.midpoint_via_float_rescaling(&self.hi)
+                }
+
+                fn half_width(&self) -> $t {
+                    float_half_width!(self.lo, self.hi)
+                }
+
+                fn split_at_mid(&self) -> (Self, Self) {
+                    let mid = self.center();
+                    (Self { lo: self.lo, hi: mid }, Self { lo: mid, hi: self.hi })
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "float_rescaling")]
+impl_for_prim_floats!(f32, f64);
+
+#[cfg(feature = "float_rescaling")]
+macro_rules! impl_midpoint_radius_for_prim_floats {
+    ($($t:ty),+) => {
+        $(
+            impl Interval<$t> {
+                /// Returns the midpoint and the radius of the interval for rigorous numerics
+                /// (e.g. interval Newton methods).
+                ///
+                /// The midpoint is guaranteed to lie inside the interval, and the radius is
+                /// rounded upwards so that `[mid - rad, mid + rad]` (in exact arithmetic)
+                /// encloses the interval. The directed rounding is implemented in software,
+                /// i.e. without switching the rounding mode of the FPU.
+                ///
+                /// Following IEEE 1788, the midpoint of an unbounded interval is `0` when both
+                #[doc = concat!("bounds are infinite and [`", stringify!($t), "::MIN`] or [`", stringify!($t), "::MAX`] otherwise")]
+                /// while the radius is infinite.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// use midpoint::Interval;
+                ///
+                /// let interval = Interval::new(-1e-300f64, 1.0).unwrap();
+                /// let (mid, rad) = interval.midpoint_radius();
+                /// assert_eq!(mid, 0.5);
+                /// // mid - (-1e-300) is not representable and is thus rounded upwards
+                /// assert_eq!(rad, 0.5f64.next_up());
+                /// ```
+                #[must_use]
+                pub fn midpoint_radius(&self) -> ($t, $t) {
+                    let (lo, hi) = (self.lo, self.hi);
+                    if lo == hi {
+                        return (lo, 0.0);
+                    }
+                    let mid = match (lo == <$t>::NEG_INFINITY, hi == <$t>::INFINITY) {
+                        (true, true) => 0.0,
+                        (true, false) => <$t>::MIN,
+                        (false, true) => <$t>::MAX,
+                        // Rounding to nearest is monotonic, so the rounded midpoint of
+                        // the bounds cannot fall outside of the interval
+                        (false, false) => lo.midpoint_via_float_rescaling(&hi),
+                    };
+                    let rad = Self::sub_rounded_upwards(hi, mid)
+                        .max(Self::sub_rounded_upwards(mid, lo));
+                    (mid, rad)
+                }
+
+                // Returns the least float that is not less than the exact difference
+                fn sub_rounded_upwards(lhs: $t, rhs: $t) -> $t {
+                    let (a, b) = (lhs, -rhs);
+                    let sum = a + b;
+                    if sum.is_infinite() {
+                        return sum;
+                    }
+                    // TwoSum algorithm (by Knuth) computes the rounding error of the sum
+                    // exactly, i.e. a + b == sum + err in exact arithmetic
+                    let a_virtual = sum - b;
+                    let b_virtual = sum - a_virtual;
+                    let err = (a - a_virtual) + (b - b_virtual);
+                    if err > 0.0 {
+                        sum.next_up()
+                    } else {
+                        sum
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "float_rescaling")]
+impl_midpoint_radius_for_prim_floats!(f32, f64);
+
+#[cfg(all(test, feature = "cpp_20_std", feature = "float_rescaling"))]
+mod tests {
+    use crate::{Interval, IntervalMidpointExt};
+
+    #[test]
+    fn center_of_full_range_of_prim_int_does_not_overflow() {
+        assert_eq!((i64::MIN..=i64::MAX).center(), -1);
+        assert_eq!((u128::MIN..=u128::MAX).center(), u128::MAX / 2);
+        assert_eq!((i8::MIN..=i8::MAX).half_width(), u8::MAX / 2);
+        let interval = Interval::new(isize::MIN, isize::MAX).unwrap();
+        assert_eq!(interval.center(), -1);
+    }
+
+    #[test]
+    fn center_of_full_range_of_prim_float_does_not_overflow() {
+        assert_eq!((f32::MIN..=f32::MAX).center(), 0.0);
+        assert_eq!((f32::MIN..=f32::MAX).half_width(), f32::MAX);
+        assert_eq!((f64::MAX..f64::MAX).center(), f64::MAX);
+    }
+
+    #[test]
+    fn half_width_of_prim_float_range_is_rounded_once() {
+        let tiny = f64::from_bits(1);
+        assert_eq!((tiny..2.0 * tiny).half_width(), 0.0);
+        assert_eq!((tiny..=2.0 * tiny).half_width(), 0.0);
+        let interval = Interval::new(tiny, 2.0 * tiny).unwrap();
+        assert_eq!(interval.half_width(), 0.0);
+    }
+
+    #[test]
+    fn split_at_mid_of_range_inclusive_of_prim_int_produces_disjoint_halves() {
+        assert_eq!((0u8..=1).split_at_mid(), (0..=0, 1..=1));
+        assert_eq!((-3i32..=-1).split_at_mid(), (-3..=-2, -1..=-1));
+
+        let (first, second) = (u8::MAX..=u8::MAX).split_at_mid();
+        assert_eq!(first, u8::MAX..=u8::MAX);
+        assert!(second.is_empty());
+
+        let (start, end) = (1u8, 0u8);
+        let (first, second) = (start..=end).split_at_mid();
+        assert!(first.is_empty() && second.is_empty());
+    }
+
+    #[test]
+    fn midpoint_radius_encloses_interval() {
+        // The bounds are chosen so that f64 arithmetic on the f32 values is exact
+        let values: [f32; 9] = [-1000.0, -3.3, -1.0, -1e-3, 0.0, 0.1, 1.0, 7.7, 1024.0];
+        for (i, &lo) in values.iter().enumerate() {
+            for &hi in &values[i..] {
+                let (mid, rad) = Interval::new(lo, hi).unwrap().midpoint_radius();
+                assert!(lo <= mid && mid <= hi);
+                let (lo, hi, mid, rad) = (lo as f64, hi as f64, mid as f64, rad as f64);
+                assert!(mid - rad <= lo && hi <= mid + rad);
+                // The radius is the least such float
+                if rad > 0.0 {
+                    let rad = (rad as f32).next_down() as f64;
+                    assert!(mid - rad > lo || hi > mid + rad);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn midpoint_radius_of_unbounded_interval_follows_ieee_1788() {
+        let entire = Interval::new(f64::NEG_INFINITY, f64::INFINITY).unwrap();
+        assert_eq!(entire.midpoint_radius(), (0.0, f64::INFINITY));
+        let interval = Interval::new(f64::NEG_INFINITY, 0.0).unwrap();
+        assert_eq!(interval.midpoint_radius(), (f64::MIN, f64::INFINITY));
+        let interval = Interval::new(f64::MAX, f64::INFINITY).unwrap();
+        assert_eq!(interval.midpoint_radius(), (f64::MAX, f64::INFINITY));
+        let interval = Interval::new(f64::MIN, f64::MAX).unwrap();
+        assert_eq!(interval.midpoint_radius(), (0.0, f64::MAX));
+    }
+
+    #[test]
+    fn split_at_mid_of_interval_shares_center() {
+        let interval = Interval::new(-1.0, 2.0).unwrap();
+        let (first, second) = interval.split_at_mid();
+        assert_eq!(first, Interval::new(-1.0, 0.5).unwrap());
+        assert_eq!(second, Interval::new(0.5, 2.0).unwrap());
+    }
+}
@@ -0,0 +1,126 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+#[cfg(feature = "bitwise")]
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "bitwise")]
+use crate::MidpointViaBitwiseOpsExt;
+#[cfg(feature = "cpp_20_std")]
+use crate::MidpointViaCpp20StdImplementationExt;
+
+/// Extension trait providing splitting of inclusive ranges of IP addresses into two halves
+/// at the midpoint.
+#[cfg(feature = "bitwise")]
+pub trait IpRangeSplitExt: Sized {
+    /// Splits the inclusive range of IP addresses into two non-empty adjacent inclusive
+    /// ranges. The midpoint is rounded down, so when the number of addresses in the range
+    /// is odd, the first half contains one address more than the second one.
+    ///
+    /// Returns [`None`] when the range contains fewer than two addresses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use midpoint::IpRangeSplitExt;
+    ///
+    /// let range = Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 255, 255);
+    /// let (lower, upper) = range.split_in_halves().unwrap();
+    /// assert_eq!(lower, Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 127, 255));
+    /// assert_eq!(upper, Ipv4Addr::new(10, 0, 128, 0)..=Ipv4Addr::new(10, 0, 255, 255));
+    /// ```
+    #[must_use]
+    fn split_in_halves(&self) -> Option<(Self, Self)>;
+}
+
+macro_rules! impl_for_ip_addr {
+    ($addr:ty, $repr:ty) => {
+        #[cfg(feature = "bitwise")]
+        impl MidpointViaBitwiseOpsExt for $addr {
+            #[doc = concat!("Returns midpoint of two [`", stringify!($addr), "`]s using algorithm using")]
+            /// [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation)
+            #[doc = concat!("on their [`", stringify!($repr), "`] representations. The result is rounded down.")]
+            fn midpoint_via_bitwise_ops(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+                let (lhs, rhs) = (<$repr>::from(*self), <$repr>::from(*rhs_ref));
+                <$addr>::from(lhs.midpoint_via_bitwise_ops(&rhs))
+            }
+        }
+
+        #[cfg(feature = "cpp_20_std")]
+        impl MidpointViaCpp20StdImplementationExt for $addr {
+            #[doc = concat!("Returns midpoint of two [`", stringify!($addr), "`]s using algorithm")]
+            /// [as suggested for  C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html)
+            #[doc = concat!("on their [`", stringify!($repr), "`] representations. The result is rounded towards left argument.")]
+            fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+                let (a, b) = (<$repr>::from(*self), <$repr>::from(*b_ref));
+                <$addr>::from(a.midpoint_via_cpp_20_std_implementation(&b))
+            }
+        }
+
+        #[cfg(feature = "bitwise")]
+        impl IpRangeSplitExt for RangeInclusive<$addr> {
+            fn split_in_halves(&self) -> Option<(Self, Self)> {
+                let (start, end) = (<$repr>::from(*self.start()), <$repr>::from(*self.end()));
+                if start >= end {
+                    return None;
+                }
+                let mid = start.midpoint_via_bitwise_ops(&end);
+                // mid < end, so mid + 1 cannot overflow
+                Some((
+                    <$addr>::from(start)..=<$addr>::from(mid),
+                    <$addr>::from(mid + 1)..=<$addr>::from(end),
+                ))
+            }
+        }
+    };
+}
+
+impl_for_ip_addr!(Ipv4Addr, u32);
+impl_for_ip_addr!(Ipv6Addr, u128);
+
+#[cfg(all(test, feature = "bitwise", feature = "cpp_20_std"))]
+mod tests {
+    use crate::IpRangeSplitExt;
+    use crate::MidpointViaBitwiseOpsExt;
+    use crate::MidpointViaCpp20StdImplementationExt;
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn midpoint_via_bitwise_ops_for_ipv4_addr_does_not_overflow() {
+        let result = Ipv4Addr::UNSPECIFIED.midpoint_via_bitwise_ops(&Ipv4Addr::BROADCAST);
+        assert_eq!(result, Ipv4Addr::new(127, 255, 255, 255));
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_ipv6_addr_rounds_towards_left_arg() {
+        let (a, b) = (Ipv6Addr::from(u128::MAX), Ipv6Addr::UNSPECIFIED);
+        let result = a.midpoint_via_cpp_20_std_implementation(&b);
+        assert_eq!(result, Ipv6Addr::from(u128::MAX / 2 + 1));
+    }
+
+    #[test]
+    fn split_in_halves_splits_whole_ipv6_address_space() {
+        let range = Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX);
+        let (lower, upper) = range.split_in_halves().unwrap();
+        assert_eq!(lower, Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX / 2));
+        assert_eq!(
+            upper,
+            Ipv6Addr::from(u128::MAX / 2 + 1)..=Ipv6Addr::from(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn split_in_halves_returns_none_for_ranges_with_fewer_than_two_addresses() {
+        let single = Ipv4Addr::BROADCAST..=Ipv4Addr::BROADCAST;
+        assert_eq!(single.split_in_halves(), None);
+        let empty = Ipv4Addr::BROADCAST..=Ipv4Addr::UNSPECIFIED;
+        assert_eq!(empty.split_in_halves(), None);
+        let pair = Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 0, 1);
+        assert_eq!(
+            pair.split_in_halves(),
+            Some((
+                Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 0, 0),
+                Ipv4Addr::new(10, 0, 0, 1)..=Ipv4Addr::new(10, 0, 0, 1)
+            ))
+        );
+    }
+}
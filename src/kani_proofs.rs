@@ -0,0 +1,191 @@
+//! [Kani](https://model-checking.github.io/kani/) proof harnesses for the midpoint algorithms
+//! for primitive integers.
+//!
+//! For every algorithm and every primitive integer type, the harnesses prove for all
+//! admissible arguments that
+//!
+//! * the computation doesn't overflow. Arithmetic overflow is checked by Kani and, under
+//!   `cfg(kani)`, `sum_without_overflow!` expands to `unchecked_add`, whose overflow is
+//!   undefined behavior also detected by Kani;
+//! * the result is rounded as documented.
+//!
+//! The documented rounding is checked against the reference model in `src/reference.rs`,
+//! which is shared with the tests in `src/verification.rs`.
+//!
+//! The harnesses can be run via `cargo kani`. They are type-checked but haven't been run yet,
+//! so the properties above are unverified.
+
+use crate::naive_midpoint_diff::NaiveMidpointDiffPrecondition;
+use crate::reference::ReferenceMidpoint;
+#[cfg(feature = "constant_time")]
+use crate::ConstantTimeMidpointExt;
+use crate::{
+    ExactMidpointExt, MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt,
+    MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaNaiveMidpointDiffExt, MidpointViaOverflowingAddExt,
+    MidpointViaPrimitivePromotionExt, NaiveMidpointExt, RoundingMode,
+};
+
+macro_rules! proofs_for {
+    (
+        $mod_name:ident,
+        $t:ty
+        $(, promotion = $promotion_trait:ident)?
+        $(, carry_rotate = $carry_rotate_trait:ident)?
+    ) => {
+        mod $mod_name {
+            use super::*;
+
+            fn expected(a: $t, b: $t, mode: RoundingMode) -> $t {
+                <$t>::expected(a, b, mode)
+            }
+
+            #[kani::proof]
+            fn midpoint_via_bitwise_ops_rounds_towards_zero() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let result = a.midpoint_via_bitwise_ops(&b);
+                assert_eq!(result, expected(a, b, RoundingMode::TowardZero));
+            }
+
+            #[kani::proof]
+            fn midpoint_via_cpp_20_std_implementation_rounds_towards_lhs() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let result = a.midpoint_via_cpp_20_std_implementation(&b);
+                assert_eq!(result, expected(a, b, RoundingMode::TowardLhs));
+            }
+
+            #[kani::proof]
+            fn midpoint_via_dietz_formula_rounds_down() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let result = a.midpoint_via_dietz_formula(&b);
+                assert_eq!(result, expected(a, b, RoundingMode::Down));
+            }
+
+            #[kani::proof]
+            fn midpoint_via_overflowing_add_rounds_down() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let result = a.midpoint_via_overflowing_add(&b);
+                assert_eq!(result, expected(a, b, RoundingMode::Down));
+            }
+
+            #[kani::proof]
+            fn midpoint_via_abs_diff_rounds_down() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let result = a.midpoint_via_abs_diff(&b);
+                assert_eq!(result, expected(a, b, RoundingMode::Down));
+            }
+
+            #[kani::proof]
+            fn naive_midpoint_rounds_towards_zero_when_sum_fits() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                kani::assume(a.checked_add(b).is_some());
+                let result = unsafe { a.naive_midpoint(&b) };
+                assert_eq!(result, expected(a, b, RoundingMode::TowardZero));
+            }
+
+            #[kani::proof]
+            fn midpoint_via_naive_midpoint_diff_rounds_towards_lhs_when_diff_fits() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                kani::assume(a.does_diff_fit(&b));
+                let result = unsafe { a.midpoint_via_naive_midpoint_diff(&b) };
+                assert_eq!(result, expected(a, b, RoundingMode::TowardLhs));
+            }
+
+            $(
+                #[kani::proof]
+                fn midpoint_via_primitive_promotion_rounds_towards_zero() {
+                    let (a, b): ($t, $t) = (kani::any(), kani::any());
+                    let result = <$t as $promotion_trait>::midpoint_via_primitive_promotion(&a, &b);
+                    assert_eq!(result, expected(a, b, RoundingMode::TowardZero));
+                }
+            )?
+
+            $(
+                #[kani::proof]
+                fn midpoint_via_carry_rotate_rounds_down() {
+                    let (a, b): ($t, $t) = (kani::any(), kani::any());
+                    let result = <$t as $carry_rotate_trait>::midpoint_via_carry_rotate(&a, &b);
+                    assert_eq!(result, expected(a, b, RoundingMode::Down));
+                }
+            )?
+
+            #[kani::proof]
+            fn exact_midpoint_is_exact() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let (floor, ceil) = <$t>::floor_and_ceil(a, b);
+                let result = a.exact_midpoint(&b);
+                assert_eq!((result.floor(), result.is_half()), (floor, floor != ceil));
+            }
+
+            #[kani::proof]
+            fn midpoint_rounded_rounds_as_specified() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let index: usize = kani::any();
+                kani::assume(index < RoundingMode::ALL.len());
+                let mode = RoundingMode::ALL[index];
+                assert_eq!(a.midpoint_rounded(&b, mode), expected(a, b, mode));
+            }
+
+            // `ct_midpoint_rounded` dispatches to the constant-time midpoint of every mode
+            #[cfg(feature = "constant_time")]
+            #[kani::proof]
+            fn ct_midpoint_rounded_rounds_as_specified() {
+                let (a, b): ($t, $t) = (kani::any(), kani::any());
+                let index: usize = kani::any();
+                kani::assume(index < RoundingMode::ALL.len());
+                let mode = RoundingMode::ALL[index];
+                assert_eq!(a.ct_midpoint_rounded(&b, mode), expected(a, b, mode));
+            }
+        }
+    };
+}
+
+proofs_for!(
+    u8_proofs,
+    u8,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+proofs_for!(
+    u16_proofs,
+    u16,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+proofs_for!(
+    u32_proofs,
+    u32,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+proofs_for!(
+    u64_proofs,
+    u64,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+proofs_for!(i8_proofs, i8, promotion = MidpointViaPrimitivePromotionExt);
+proofs_for!(
+    i16_proofs,
+    i16,
+    promotion = MidpointViaPrimitivePromotionExt
+);
+proofs_for!(
+    i32_proofs,
+    i32,
+    promotion = MidpointViaPrimitivePromotionExt
+);
+proofs_for!(
+    i64_proofs,
+    i64,
+    promotion = MidpointViaPrimitivePromotionExt
+);
+// usize, isize, u128 and i128 don't have a primitive promotion
+proofs_for!(
+    usize_proofs,
+    usize,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+proofs_for!(isize_proofs, isize);
+proofs_for!(u128_proofs, u128, carry_rotate = MidpointViaCarryRotateExt);
+proofs_for!(i128_proofs, i128);
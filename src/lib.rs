@@ -17,31 +17,145 @@
     feature(const_inherent_unchecked_arith)
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 mod common_macros;
 
+#[cfg(feature = "abs_diff")]
+mod abs_diff;
+#[cfg(feature = "algorithm")]
+mod algorithm;
+#[cfg(feature = "bitwise")]
 mod bitwise;
+#[cfg(feature = "carry_rotate")]
+mod carry_rotate;
+#[cfg(all(midpoint_codegen_probes, feature = "algorithm", feature = "constant_time"))]
+mod codegen_probes;
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "constant_time")]
+mod constant_time;
+#[cfg(feature = "cpp_20_std")]
 mod cpp_20_std;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "dietz")]
+mod dietz;
+#[cfg(any(feature = "bitwise", feature = "cpp_20_std"))]
+mod duration;
+#[cfg(feature = "exact")]
+mod exact;
+#[cfg(feature = "float_rescaling")]
+mod float_rescaling;
+#[cfg(all(feature = "half", feature = "float_rescaling"))]
+mod half_precision;
+#[cfg(any(feature = "cpp_20_std", feature = "float_rescaling"))]
+mod interval;
+#[cfg(any(feature = "bitwise", feature = "cpp_20_std"))]
+mod ip;
+#[cfg(all(kani, feature = "algorithm", feature = "exact"))]
+mod kani_proofs;
+#[cfg(feature = "naive")]
 mod naive;
+#[cfg(feature = "naive_midpoint_diff")]
 mod naive_midpoint_diff;
+#[cfg(feature = "overflowing_add")]
+mod overflowing_add;
+#[cfg(feature = "swar")]
+mod pixel;
+mod point;
+#[cfg(feature = "primitive_promotion")]
 mod primitive_promotion;
+#[cfg(any(
+    all(test, feature = "algorithm", feature = "float_rescaling", feature = "exact"),
+    feature = "proptest"
+))]
+mod property;
+#[cfg(feature = "num-rational")]
+mod ratio;
+#[cfg(all(any(test, kani), feature = "algorithm", feature = "exact"))]
+mod reference;
+mod rounding;
+#[cfg(all(feature = "std", feature = "cpp_20_std"))]
+mod std_time;
+#[cfg(feature = "swar")]
+mod swar;
+#[cfg(any(feature = "bitwise", feature = "cpp_20_std"))]
+mod unicode_scalar;
+#[cfg(all(test, feature = "algorithm", feature = "exact"))]
+mod verification;
+#[cfg(all(test, feature = "algorithm", feature = "exact"))]
+mod xorshift;
 
 // crate:: disambiguates primitive_promotion as the module import source (as opposed to crate import source)
 // Note: crate in this context is THIS crate (akin to self:: for this module and super:: for parent module)
+#[cfg(feature = "abs_diff")]
+pub use crate::abs_diff::MidpointViaAbsDiffExt;
+#[cfg(feature = "algorithm")]
+pub use crate::algorithm::{
+    midpoint_with, Algorithm, AlgorithmInfo, AlgorithmMidpointExt, MidpointError, ALGORITHMS,
+};
+#[cfg(feature = "bitwise")]
 pub use crate::bitwise::MidpointViaBitwiseOpsExt;
+#[cfg(feature = "carry_rotate")]
+pub use crate::carry_rotate::MidpointViaCarryRotateExt;
+#[cfg(feature = "subtle")]
+pub use crate::constant_time::ConditionallySelectableMidpointExt;
+#[cfg(feature = "constant_time")]
+pub use crate::constant_time::ConstantTimeMidpointExt;
+#[cfg(feature = "cpp_20_std")]
 pub use crate::cpp_20_std::MidpointViaCpp20StdImplementationExt;
+#[cfg(feature = "rust_decimal")]
+pub use crate::decimal::DecimalMidpointExt;
+#[cfg(feature = "dietz")]
+pub use crate::dietz::MidpointViaDietzFormulaExt;
+#[cfg(feature = "exact")]
+pub use crate::exact::{ExactMidpointExt, HalfInt};
+#[cfg(feature = "float_rescaling")]
+pub use crate::float_rescaling::MidpointViaFloatRescalingExt;
+#[cfg(any(feature = "cpp_20_std", feature = "float_rescaling"))]
+pub use crate::interval::{Interval, IntervalMidpointExt};
+#[cfg(feature = "bitwise")]
+pub use crate::ip::IpRangeSplitExt;
+#[cfg(feature = "naive")]
 pub use crate::naive::NaiveMidpointExt;
+#[cfg(feature = "naive_midpoint_diff")]
 pub use crate::naive_midpoint_diff::MidpointViaNaiveMidpointDiffExt;
+#[cfg(feature = "overflowing_add")]
+pub use crate::overflowing_add::MidpointViaOverflowingAddExt;
+#[cfg(feature = "swar")]
+pub use crate::pixel::PixelMidpointExt;
+pub use crate::point::PointMidpointExt;
+#[cfg(feature = "primitive_promotion")]
 pub use crate::primitive_promotion::MidpointViaPrimitivePromotionExt;
+#[cfg(feature = "proptest")]
+pub use crate::property::{
+    check_commutativity, check_idempotence, check_odd_symmetry, check_within_bounds, limit_biased,
+};
+#[cfg(feature = "num-rational")]
+pub use crate::ratio::CheckedRatioMidpointExt;
+pub use crate::rounding::RoundingMode;
+#[cfg(feature = "swar")]
+pub use crate::swar::MidpointViaSwarExt;
 
-#[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+#[cfg(all(feature = "abs_diff", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+pub use crate::abs_diff::midpoint_via_abs_diff;
+#[cfg(all(feature = "bitwise", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
 pub use crate::bitwise::midpoint_via_bitwise_ops;
-#[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+#[cfg(all(feature = "cpp_20_std", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
 pub use crate::cpp_20_std::midpoint_via_cpp_20_std_implementation;
-#[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+#[cfg(all(feature = "dietz", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+pub use crate::dietz::midpoint_via_dietz_formula;
+#[cfg(all(feature = "float_rescaling", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+pub use crate::float_rescaling::midpoint_via_float_rescaling;
+#[cfg(all(feature = "naive", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
 pub use crate::naive::naive_midpoint;
-#[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+#[cfg(all(feature = "naive_midpoint_diff", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
 pub use crate::naive_midpoint_diff::midpoint_via_naive_midpoint_diff;
-#[cfg(any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound")))]
+#[cfg(all(feature = "overflowing_add", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
+pub use crate::overflowing_add::midpoint_via_overflowing_add;
+#[cfg(all(feature = "primitive_promotion", any(doc, test, doctest, all(feature = "const_trait_impl", feature = "const_fn_trait_bound"))))]
 pub use crate::primitive_promotion::midpoint_via_primitive_promotion;
 
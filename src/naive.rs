@@ -1,3 +1,5 @@
+use crate::RoundingMode;
+
 try_impl_unsafe_generic_const_fn_for_trait!(
     NaiveMidpointExt::naive_midpoint
 );
@@ -5,6 +7,13 @@ try_impl_unsafe_generic_const_fn_for_trait!(
 /// Extension trait providing implementation of naive midpoint algorithm.
 /// For primitive integers, the result is rounded towards zero.
 pub trait NaiveMidpointExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::TowardZero;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
     /// Returns midpoint using naive algorithm. For primitive integers, the result is
     /// rounded towards zero.
     ///
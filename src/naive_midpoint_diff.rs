@@ -1,5 +1,7 @@
 use epsi::EquisizedPrimitiveSignedIntExt as EPSI;
 
+use crate::RoundingMode;
+
 try_impl_unsafe_generic_const_fn_for_trait!(
     MidpointViaNaiveMidpointDiffExt::midpoint_via_naive_midpoint_diff
 );
@@ -7,6 +9,13 @@ try_impl_unsafe_generic_const_fn_for_trait!(
 /// Extension trait providing implementation of midpoint algorithm via naive midpoint
 /// difference. For primitive integers, the result is rounded towards left argument.
 pub trait MidpointViaNaiveMidpointDiffExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::TowardLhs;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
     /// Returns midpoint using algorithm naively relying on the difference of arguments.
     /// For primitive integers, the result is rounded towards left argument.
     ///
@@ -56,7 +65,9 @@ macro_rules! impl_midpoint_fn_for_t {
             let (lhs, rhs) = (*self, *rhs_ref);
             let arg_diff = rhs.wrapping_sub(lhs) as <Self as EPSI>::EquisizedPrimitiveSignedInt;
             let midpoint_diff = (arg_diff / 2) as Self;
-            lhs + midpoint_diff
+            // For unsigned types, negative midpoint_diff wraps around, so the addition
+            // must wrap around as well
+            lhs.wrapping_add(midpoint_diff)
         }
     };
 }
@@ -66,6 +77,39 @@ impl_for_all_prim_ints!(
     fn macro = impl_midpoint_fn_for_t
 );
 
+/// Precondition of [`MidpointViaNaiveMidpointDiffExt::midpoint_via_naive_midpoint_diff`]
+/// for primitive integers, shared by [`Algorithm`](crate::Algorithm) and the checks
+/// of the algorithm.
+#[cfg(feature = "algorithm")]
+pub(crate) trait NaiveMidpointDiffPrecondition {
+    /// Returns whether the difference of the second argument and the first argument fits
+    /// in <Self as [EPSI]>::EquisizedPrimitiveSignedInt.
+    fn does_diff_fit(&self /*lhs_ref*/, rhs_ref: &Self) -> bool;
+}
+
+#[cfg(feature = "algorithm")]
+macro_rules! impl_naive_midpoint_diff_precondition_for_types {
+    ($($t:ty),+) => {
+        $(
+            impl NaiveMidpointDiffPrecondition for $t {
+                fn does_diff_fit(&self /*lhs_ref*/, rhs_ref: &Self) -> bool {
+                    let (lhs, rhs) = (*self, *rhs_ref);
+                    if rhs >= lhs {
+                        rhs.abs_diff(lhs) <= <Self as EPSI>::EquisizedPrimitiveSignedInt::MAX.unsigned_abs()
+                    } else {
+                        lhs.abs_diff(rhs) <= <Self as EPSI>::EquisizedPrimitiveSignedInt::MIN.unsigned_abs()
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "algorithm")]
+impl_naive_midpoint_diff_precondition_for_types!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
 #[cfg(test)]
 mod tests {
     use crate::MidpointViaNaiveMidpointDiffExt;
@@ -108,4 +152,11 @@ mod tests {
         let result = unsafe { (lhs).midpoint_via_naive_midpoint_diff(&rhs) };
         assert_eq!(result, i32::MIN / 2);
     }
+
+    #[test]
+    fn midpoint_via_naive_midpoint_diff_does_not_overflow_when_unsigned_lhs_exceeds_rhs() {
+        // The negative midpoint difference wraps around to a large unsigned value
+        let result: u8 = unsafe { 3.midpoint_via_naive_midpoint_diff(&1) };
+        assert_eq!(result, 2);
+    }
 }
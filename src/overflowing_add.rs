@@ -0,0 +1,97 @@
+use epui::EquisizedPrimitiveUnsignedIntExt as EPUI;
+
+use crate::RoundingMode;
+
+try_impl_generic_const_fn_for_trait!(MidpointViaOverflowingAddExt::midpoint_via_overflowing_add);
+
+/// Extension trait providing implementation of midpoint algorithm via
+/// [`overflowing_add`](u32::overflowing_add), which shifts the carry (or, for signed
+/// integers, the sign of the exact sum) back into the halved wrapping sum.
+/// For primitive integers, the result is rounded down.
+pub trait MidpointViaOverflowingAddExt {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::Down;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
+    /// Returns midpoint using algorithm based on [`overflowing_add`](u32::overflowing_add),
+    /// which shifts the carry (or, for signed integers, the sign of the exact sum) back
+    /// into the halved wrapping sum. For primitive integers, the result is rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaOverflowingAddExt;
+    ///
+    /// let result: u8 = 255.midpoint_via_overflowing_add(&254);
+    /// assert_eq!(result, 254);
+    /// ```
+    #[must_use]
+    fn midpoint_via_overflowing_add(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+macro_rules! impl_midpoint_fn_for_t {
+    () => {
+        // Type limit comparisons are deemed useless for unsigned types
+        // by the compiler but they are necessary for signed types
+        #[allow(unused_comparisons)]
+        fn midpoint_via_overflowing_add(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // At the time of writing, explicit dereferencing is necessary because
+            // `<&u8 as Add<&u8>>::add` is not yet stable as a const fn
+            // and requires `#![feature(const_ops)]`
+            //
+            // Rust unstable book entry:
+            // https://doc.rust-lang.org/beta/unstable-book/library-features/const-ops.html
+            let (lhs, rhs) = (*self, *rhs_ref);
+            let (wrapping_sum, overflow) = lhs.overflowing_add(rhs);
+            // The exact sum takes one more bit than the arguments. For unsigned integers,
+            // that bit is the carry. For signed integers, it is the sign of the exact sum,
+            // which is the sign of the wrapping sum unless the addition overflowed
+            let top_bit = overflow ^ (wrapping_sum < 0);
+            let halved_sum = (wrapping_sum as <Self as EPUI>::EquisizedPrimitiveUnsignedInt) >> 1;
+            let top_bit =
+                (top_bit as <Self as EPUI>::EquisizedPrimitiveUnsignedInt) << (Self::BITS - 1);
+            (halved_sum | top_bit) as Self
+        }
+    };
+}
+
+impl_for_all_prim_ints!(
+    trait = MidpointViaOverflowingAddExt,
+    fn macro = impl_midpoint_fn_for_t
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaOverflowingAddExt;
+
+    #[test]
+    fn midpoint_via_overflowing_add_rounds_down_including_when_args_are_positive() {
+        let result: i32 = 2.midpoint_via_overflowing_add(&3);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn midpoint_via_overflowing_add_rounds_down_including_when_args_are_negative() {
+        let result: i32 = (-3).midpoint_via_overflowing_add(&-2);
+        assert_eq!(result, -3);
+    }
+
+    #[test]
+    fn midpoint_via_overflowing_add_shifts_in_carry_or_sign() {
+        assert_eq!(
+            u128::MAX.midpoint_via_overflowing_add(&u128::MAX),
+            u128::MAX
+        );
+        assert_eq!(
+            i128::MIN.midpoint_via_overflowing_add(&i128::MIN),
+            i128::MIN
+        );
+        assert_eq!(
+            i128::MAX.midpoint_via_overflowing_add(&i128::MAX),
+            i128::MAX
+        );
+    }
+}
@@ -0,0 +1,140 @@
+mod srgb_lut;
+
+use crate::MidpointViaSwarExt;
+
+use self::srgb_lut::{LINEAR_THRESHOLDS, SRGB_TO_LINEAR};
+
+/// Extension trait providing midpoint of RGBA pixels with 8 bits per channel.
+///
+/// The trait is implemented for
+///
+/// * `[u8; 4]`, where the channels are stored in the order `[r, g, b, a]`;
+/// * `u32`, where the channels are packed as `0xRRGGBBAA`, i.e. as by
+///   [`u32::from_be_bytes`]`([r, g, b, a])`.
+///
+/// Alpha is assumed to be straight (i.e. not premultiplied) and is always averaged linearly.
+pub trait PixelMidpointExt {
+    /// Returns the per-channel midpoint of two pixels, where each channel is rounded down.
+    ///
+    /// The channels are averaged as they are, which is what `pavgb`-like instructions do
+    /// (save for rounding). For sRGB-encoded pixels, consider
+    /// [`PixelMidpointExt::srgb_pixel_midpoint`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::PixelMidpointExt;
+    ///
+    /// assert_eq!(0xFF_00_80_FFu32.pixel_midpoint(&0xFF_FF_81_00), 0xFF_7F_80_7F);
+    /// assert_eq!([255u8, 0, 128, 255].pixel_midpoint(&[255, 255, 129, 0]), [255, 127, 128, 127]);
+    /// ```
+    #[must_use]
+    fn pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the gamma-correct midpoint of two sRGB-encoded pixels.
+    ///
+    /// The color channels are converted to linear intensities, averaged, and converted back
+    /// with rounding to nearest via lookup tables, so that neither floating-point arithmetic
+    /// nor `std` is necessary. The alpha channel is averaged as in
+    /// [`PixelMidpointExt::pixel_midpoint`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::PixelMidpointExt;
+    ///
+    /// // The midpoint of black and white is brighter than 127 in sRGB
+    /// let (black, white) = ([0u8, 0, 0, 255], [255u8, 255, 255, 255]);
+    /// assert_eq!(black.srgb_pixel_midpoint(&white), [188, 188, 188, 255]);
+    /// ```
+    #[must_use]
+    fn srgb_pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+fn srgb_channel_midpoint(lhs: u8, rhs: u8) -> u8 {
+    let linear_sum = SRGB_TO_LINEAR[lhs as usize] as u32 + SRGB_TO_LINEAR[rhs as usize] as u32;
+    // The encoded midpoint exceeds k if and only if the exact linear midpoint,
+    // i.e. linear_sum / 2, reaches the k-th threshold
+    LINEAR_THRESHOLDS.partition_point(|&threshold| 2 * threshold as u32 <= linear_sum) as u8
+}
+
+// The sum of two channels always fits into u16, so the midpoint is rounded down
+fn channel_midpoint(lhs: u8, rhs: u8) -> u8 {
+    ((lhs as u16 + rhs as u16) / 2) as u8
+}
+
+impl PixelMidpointExt for [u8; 4] {
+    fn pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        core::array::from_fn(|i| channel_midpoint(self[i], rhs_ref[i]))
+    }
+
+    fn srgb_pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        core::array::from_fn(|i| match i {
+            3 => channel_midpoint(self[i], rhs_ref[i]),
+            _ => srgb_channel_midpoint(self[i], rhs_ref[i]),
+        })
+    }
+}
+
+impl PixelMidpointExt for u32 {
+    fn pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        // All four channels are averaged at once
+        self.u8_lanes_midpoint_down(rhs_ref)
+    }
+
+    fn srgb_pixel_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        let (lhs, rhs) = (self.to_be_bytes(), rhs_ref.to_be_bytes());
+        u32::from_be_bytes(lhs.srgb_pixel_midpoint(&rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::srgb_lut::{LINEAR_THRESHOLDS, SRGB_TO_LINEAR};
+    use crate::PixelMidpointExt;
+
+    #[test]
+    fn pixel_midpoint_of_packed_pixels_matches_per_channel_midpoint() {
+        let pixels = [
+            0u32,
+            0xFF_FF_FF_FF,
+            0x01_02_03_04,
+            0xFE_01_7F_80,
+            0x80_80_80_81,
+        ];
+        for a in pixels {
+            for b in pixels {
+                let expected = a.to_be_bytes().pixel_midpoint(&b.to_be_bytes());
+                assert_eq!(a.pixel_midpoint(&b).to_be_bytes(), expected);
+            }
+        }
+        assert_eq!(
+            [255u8, 1, 0, 3].pixel_midpoint(&[255, 0, 0, 0]),
+            [255, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn srgb_pixel_midpoint_is_identity_for_equal_pixels() {
+        for c in 0..=u8::MAX {
+            let pixel = [c, c, c, c];
+            assert_eq!(pixel.srgb_pixel_midpoint(&pixel), pixel);
+        }
+    }
+
+    #[test]
+    fn srgb_lookup_tables_are_consistent() {
+        assert_eq!(SRGB_TO_LINEAR[0], 0);
+        assert_eq!(SRGB_TO_LINEAR[255], u16::MAX);
+        for (k, window) in SRGB_TO_LINEAR.windows(2).enumerate() {
+            // The threshold between two adjacent codes lies between their linear intensities
+            assert!(window[0] < LINEAR_THRESHOLDS[k] && LINEAR_THRESHOLDS[k] <= window[1]);
+        }
+    }
+
+    #[test]
+    fn srgb_pixel_midpoint_keeps_alpha_linear() {
+        let (a, b) = (0x00_00_00_00u32, 0xFF_FF_FF_FF);
+        assert_eq!(a.srgb_pixel_midpoint(&b), 0xBC_BC_BC_7F);
+    }
+}
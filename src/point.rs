@@ -0,0 +1,223 @@
+/// Extension trait providing component-wise midpoint of points and vectors.
+///
+/// The midpoint of every coordinate is computed with the same supplied function,
+/// e.g. `i32::midpoint_via_bitwise_ops` or `f64::midpoint_via_float_rescaling`, so
+/// a single rounding mode is applied consistently to all coordinates.
+pub trait PointMidpointExt {
+    /// Type of the coordinates of the point.
+    type Coordinate;
+
+    /// Returns the point whose coordinates are midpoints of the respective coordinates of
+    /// the arguments, as computed by `midpoint_fn`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{MidpointViaBitwiseOpsExt, PointMidpointExt};
+    ///
+    /// let result = [0, 2, 7].point_midpoint_with(&[u32::MAX, 4, 8], u32::midpoint_via_bitwise_ops);
+    /// assert_eq!(result, [u32::MAX / 2, 3, 7]);
+    /// ```
+    #[must_use]
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, midpoint_fn: F) -> Self
+    where
+        F: FnMut(&Self::Coordinate, &Self::Coordinate) -> Self::Coordinate;
+}
+
+impl<T, const N: usize> PointMidpointExt for [T; N] {
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        core::array::from_fn(|i| midpoint_fn(&self[i], &rhs_ref[i]))
+    }
+}
+
+impl<T> PointMidpointExt for (T, T) {
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        (
+            midpoint_fn(&self.0, &rhs_ref.0),
+            midpoint_fn(&self.1, &rhs_ref.1),
+        )
+    }
+}
+
+impl<T> PointMidpointExt for (T, T, T) {
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        (
+            midpoint_fn(&self.0, &rhs_ref.0),
+            midpoint_fn(&self.1, &rhs_ref.1),
+            midpoint_fn(&self.2, &rhs_ref.2),
+        )
+    }
+}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_for_mint_types {
+    ($( $mint_type:ident { $($field:ident),+ } ),+) => {
+        $(
+            impl<T> PointMidpointExt for mint::$mint_type<T> {
+                type Coordinate = T;
+
+                fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+                where
+                    F: FnMut(&T, &T) -> T,
+                {
+                    mint::$mint_type {
+                        $( $field: midpoint_fn(&self.$field, &rhs_ref.$field), )+
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_for_mint_types!(
+    Point2 { x, y },
+    Point3 { x, y, z },
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector4 { x, y, z, w }
+);
+
+#[cfg(feature = "glam")]
+macro_rules! impl_for_glam_types {
+    ($t:ty, [$($glam_type:ident),+]) => {
+        $(
+            impl PointMidpointExt for glam::$glam_type {
+                type Coordinate = $t;
+
+                fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, midpoint_fn: F) -> Self
+                where
+                    F: FnMut(&$t, &$t) -> $t,
+                {
+                    Self::from_array(
+                        self.to_array()
+                            .point_midpoint_with(&rhs_ref.to_array(), midpoint_fn),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_for_glam_types!(f32, [Vec2, Vec3, Vec3A, Vec4]);
+#[cfg(feature = "glam")]
+impl_for_glam_types!(f64, [DVec2, DVec3, DVec4]);
+#[cfg(feature = "glam")]
+impl_for_glam_types!(i32, [IVec2, IVec3, IVec4]);
+#[cfg(feature = "glam")]
+impl_for_glam_types!(u32, [UVec2, UVec3, UVec4]);
+#[cfg(feature = "glam")]
+impl_for_glam_types!(i64, [I64Vec2, I64Vec3, I64Vec4]);
+#[cfg(feature = "glam")]
+impl_for_glam_types!(u64, [U64Vec2, U64Vec3, U64Vec4]);
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar, const R: usize, const C: usize> PointMidpointExt
+    for nalgebra::SMatrix<T, R, C>
+{
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, mut midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        self.zip_map(rhs_ref, |lhs, rhs| midpoint_fn(&lhs, &rhs))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar, const D: usize> PointMidpointExt for nalgebra::Point<T, D> {
+    type Coordinate = T;
+
+    fn point_midpoint_with<F>(&self /*lhs_ref*/, rhs_ref: &Self, midpoint_fn: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        Self::from(
+            self.coords
+                .point_midpoint_with(&rhs_ref.coords, midpoint_fn),
+        )
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "bitwise",
+    feature = "cpp_20_std",
+    feature = "float_rescaling"
+))]
+mod tests {
+    use crate::{
+        MidpointViaBitwiseOpsExt, MidpointViaCpp20StdImplementationExt,
+        MidpointViaFloatRescalingExt, PointMidpointExt,
+    };
+
+    #[test]
+    fn point_midpoint_with_applies_midpoint_fn_to_every_coordinate_of_array() {
+        let result =
+            [u8::MAX, 0, 3].point_midpoint_with(&[u8::MAX, 1, 6], u8::midpoint_via_bitwise_ops);
+        assert_eq!(result, [u8::MAX, 0, 4]);
+    }
+
+    #[test]
+    fn point_midpoint_with_applies_midpoint_fn_to_every_coordinate_of_tuple() {
+        let result = (3i64, -3i64)
+            .point_midpoint_with(&(2, -2), i64::midpoint_via_cpp_20_std_implementation);
+        assert_eq!(result, (3, -3));
+        let result = (f64::MAX, 1.0, -1.0)
+            .point_midpoint_with(&(f64::MAX, 2.0, -2.0), f64::midpoint_via_float_rescaling);
+        assert_eq!(result, (f64::MAX, 1.5, -1.5));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn point_midpoint_with_supports_mint_types() {
+        let (a, b) = (
+            mint::Point2 { x: 0u32, y: 2 },
+            mint::Point2 { x: u32::MAX, y: 4 },
+        );
+        let result = a.point_midpoint_with(&b, u32::midpoint_via_bitwise_ops);
+        assert_eq!(
+            result,
+            mint::Point2 {
+                x: u32::MAX / 2,
+                y: 3
+            }
+        );
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn point_midpoint_with_supports_glam_types() {
+        let (a, b) = (glam::IVec2::new(-4, 2), glam::IVec2::new(2, 4));
+        let result = a.point_midpoint_with(&b, i32::midpoint_via_bitwise_ops);
+        assert_eq!(result, glam::IVec2::new(-1, 3));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn point_midpoint_with_supports_nalgebra_types() {
+        let (a, b) = (
+            nalgebra::Point3::new(f32::MAX, 1.0, 0.0),
+            nalgebra::Point3::new(f32::MAX, 2.0, 0.0),
+        );
+        let result = a.point_midpoint_with(&b, f32::midpoint_via_float_rescaling);
+        assert_eq!(result, nalgebra::Point3::new(f32::MAX, 1.5, 0.0));
+    }
+}
@@ -1,5 +1,7 @@
 use primitive_promotion::PrimitivePromotionExt as PP;
 
+use crate::RoundingMode;
+
 try_impl_generic_const_fn_for_trait!(
     MidpointViaPrimitivePromotionExt::midpoint_via_primitive_promotion
 );
@@ -7,6 +9,13 @@ try_impl_generic_const_fn_for_trait!(
 /// Extension trait providing implementation of midpoint algorithm via [primitive promotion][PP].
 /// For primitive integers, the result is rounded towards zero.
 pub trait MidpointViaPrimitivePromotionExt: PP {
+    /// Rounding behavior of the algorithm for primitive integers.
+    const ROUNDING: RoundingMode = RoundingMode::TowardZero;
+    /// See [`RoundingMode::is_commutative`].
+    const IS_COMMUTATIVE: bool = Self::ROUNDING.is_commutative();
+    /// See [`RoundingMode::is_odd_symmetric`].
+    const IS_ODD_SYMMETRIC: bool = Self::ROUNDING.is_odd_symmetric();
+
     /// Returns midpoint using algorithm based on [primitive promotion][PP].
     /// For primitive integers, the result is rounded towards zero.
     ///
@@ -0,0 +1,383 @@
+use core::fmt::Debug;
+
+use proptest::prelude::{any, prop_assert, prop_assert_eq, prop_oneof, Arbitrary, Just, Strategy};
+use proptest::test_runner::TestCaseError;
+
+/// Returns a [`Strategy`] generating arbitrary values of `T` where `min` and `max` are
+/// generated more often than by [`any`], since the midpoint algorithms are the most
+/// likely to overflow near the limits of the type.
+///
+/// # Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use midpoint::{check_within_bounds, limit_biased, MidpointViaBitwiseOpsExt};
+///
+/// proptest!(|(a in limit_biased(i32::MIN, i32::MAX), b in limit_biased(i32::MIN, i32::MAX))| {
+///     check_within_bounds(&a, &b, i32::midpoint_via_bitwise_ops)?;
+/// });
+/// ```
+pub fn limit_biased<T>(min: T, max: T) -> impl Strategy<Value = T>
+where
+    T: Arbitrary + Clone + Debug,
+{
+    prop_oneof![
+        4 => any::<T>(),
+        1 => Just(min),
+        1 => Just(max),
+    ]
+}
+
+/// Checks that the midpoint lies between the arguments, i.e.
+/// `min(a, b) <= midpoint(a, b) <= max(a, b)`.
+///
+/// This property is expected to hold for every midpoint algorithm as long as
+/// its preconditions are met.
+pub fn check_within_bounds<T, F>(a: &T, b: &T, midpoint_fn: F) -> Result<(), TestCaseError>
+where
+    T: PartialOrd + Debug,
+    F: FnOnce(&T, &T) -> T,
+{
+    let (min, max) = if a <= b { (a, b) } else { (b, a) };
+    let midpoint = midpoint_fn(a, b);
+    prop_assert!(
+        min <= &midpoint && &midpoint <= max,
+        "midpoint({:?}, {:?}) == {:?}",
+        a,
+        b,
+        midpoint
+    );
+    Ok(())
+}
+
+/// Checks that the midpoint of a value with itself is the value, i.e. `midpoint(a, a) == a`.
+///
+/// This property is expected to hold for every midpoint algorithm as long as
+/// its preconditions are met.
+pub fn check_idempotence<T, F>(a: &T, midpoint_fn: F) -> Result<(), TestCaseError>
+where
+    T: PartialEq + Debug,
+    F: FnOnce(&T, &T) -> T,
+{
+    prop_assert_eq!(&midpoint_fn(a, a), a);
+    Ok(())
+}
+
+/// Checks that the order of the arguments doesn't matter, i.e.
+/// `midpoint(a, b) == midpoint(b, a)`.
+///
+/// According to DESIGN.md, this property holds for the rounding behaviors that
+/// don't depend on the order of arguments, e.g. rounding towards zero, but not for
+/// rounding towards the first (`lhs`) or the second (`rhs`) argument.
+pub fn check_commutativity<T, F>(a: &T, b: &T, mut midpoint_fn: F) -> Result<(), TestCaseError>
+where
+    T: PartialEq + Debug,
+    F: FnMut(&T, &T) -> T,
+{
+    prop_assert_eq!(midpoint_fn(a, b), midpoint_fn(b, a));
+    Ok(())
+}
+
+/// Checks that the midpoint commutes with negation, i.e. `midpoint(a, b) == -midpoint(-a, -b)`,
+/// provided that the negation of the arguments and the result is defined.
+///
+/// `checked_neg` returns [`None`] when the negation is not representable (e.g. for the
+/// minimum of a signed integer type), in which case the check is skipped.
+///
+/// The property holds for rounding towards zero, towards either argument, and towards even
+/// but not for rounding up or down, as reported by
+/// [`RoundingMode::is_odd_symmetric`](crate::RoundingMode::is_odd_symmetric).
+pub fn check_odd_symmetry<T, F, N>(
+    a: &T,
+    b: &T,
+    mut midpoint_fn: F,
+    checked_neg: N,
+) -> Result<(), TestCaseError>
+where
+    T: PartialEq + Debug,
+    F: FnMut(&T, &T) -> T,
+    N: Fn(&T) -> Option<T>,
+{
+    if let (Some(neg_a), Some(neg_b)) = (checked_neg(a), checked_neg(b)) {
+        let midpoint = midpoint_fn(a, b);
+        if let Some(neg_midpoint_of_negs) = checked_neg(&midpoint_fn(&neg_a, &neg_b)) {
+            prop_assert_eq!(midpoint, neg_midpoint_of_negs);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(
+    test,
+    feature = "algorithm",
+    feature = "float_rescaling",
+    feature = "exact"
+))]
+mod tests {
+    use core::net::{Ipv4Addr, Ipv6Addr};
+    use core::time::Duration;
+
+    use super::{
+        check_commutativity, check_idempotence, check_odd_symmetry, check_within_bounds,
+        limit_biased,
+    };
+    use crate::naive_midpoint_diff::NaiveMidpointDiffPrecondition;
+    use crate::{
+        ExactMidpointExt, Interval, IntervalMidpointExt, MidpointViaAbsDiffExt,
+        MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt, MidpointViaCpp20StdImplementationExt,
+        MidpointViaDietzFormulaExt, MidpointViaFloatRescalingExt, MidpointViaNaiveMidpointDiffExt,
+        MidpointViaOverflowingAddExt, MidpointViaPrimitivePromotionExt, NaiveMidpointExt,
+        RoundingMode,
+    };
+    use proptest::prelude::*;
+
+    macro_rules! int_properties {
+        (
+            $t:ty,
+            $checked_neg:expr
+            $(, promotion = $promotion_trait:ident)?
+            $(, carry_rotate = $carry_rotate_trait:ident)?
+        ) => {
+            proptest!(|(a in limit_biased(<$t>::MIN, <$t>::MAX), b in limit_biased(<$t>::MIN, <$t>::MAX))| {
+                for mode in RoundingMode::ALL {
+                    let midpoint_fn = |a: &$t, b: &$t| a.midpoint_rounded(b, mode);
+                    check_within_bounds(&a, &b, midpoint_fn)?;
+                    check_idempotence(&a, midpoint_fn)?;
+                    if mode.is_commutative() {
+                        check_commutativity(&a, &b, midpoint_fn)?;
+                    }
+                    if mode.is_odd_symmetric() {
+                        check_odd_symmetry(&a, &b, midpoint_fn, $checked_neg)?;
+                    }
+                }
+
+                check_within_bounds(&a, &b, <$t>::midpoint_via_bitwise_ops)?;
+                check_idempotence(&a, <$t>::midpoint_via_bitwise_ops)?;
+                check_commutativity(&a, &b, <$t>::midpoint_via_bitwise_ops)?;
+                check_odd_symmetry(&a, &b, <$t>::midpoint_via_bitwise_ops, $checked_neg)?;
+
+                let cpp_20_midpoint_fn = <$t>::midpoint_via_cpp_20_std_implementation;
+                check_within_bounds(&a, &b, cpp_20_midpoint_fn)?;
+                check_idempotence(&a, cpp_20_midpoint_fn)?;
+                check_odd_symmetry(&a, &b, cpp_20_midpoint_fn, $checked_neg)?;
+
+                // Rounding down is commutative but not odd-symmetric
+                let rounded_down_midpoint_fns: [fn(&$t, &$t) -> $t; 3] = [
+                    <$t>::midpoint_via_dietz_formula,
+                    <$t>::midpoint_via_overflowing_add,
+                    <$t>::midpoint_via_abs_diff,
+                ];
+                for midpoint_fn in rounded_down_midpoint_fns {
+                    check_within_bounds(&a, &b, midpoint_fn)?;
+                    check_idempotence(&a, midpoint_fn)?;
+                    check_commutativity(&a, &b, midpoint_fn)?;
+                }
+                $(
+                    let carry_rotate_midpoint_fn =
+                        <$t as $carry_rotate_trait>::midpoint_via_carry_rotate;
+                    check_within_bounds(&a, &b, carry_rotate_midpoint_fn)?;
+                    check_idempotence(&a, carry_rotate_midpoint_fn)?;
+                    check_commutativity(&a, &b, carry_rotate_midpoint_fn)?;
+                )?
+
+                $(
+                    let promotion_midpoint_fn =
+                        <$t as $promotion_trait>::midpoint_via_primitive_promotion;
+                    check_within_bounds(&a, &b, promotion_midpoint_fn)?;
+                    check_idempotence(&a, promotion_midpoint_fn)?;
+                    check_commutativity(&a, &b, promotion_midpoint_fn)?;
+                    check_odd_symmetry(&a, &b, promotion_midpoint_fn, $checked_neg)?;
+                )?
+
+                // The unsafe implementations are checked only when their preconditions hold
+                // for all the arguments they are called with
+                let naive_midpoint_fn = |a: &$t, b: &$t| unsafe { a.naive_midpoint(b) };
+                if a.checked_add(a).is_some() {
+                    check_idempotence(&a, naive_midpoint_fn)?;
+                }
+                if a.checked_add(b).is_some() {
+                    check_within_bounds(&a, &b, naive_midpoint_fn)?;
+                    check_commutativity(&a, &b, naive_midpoint_fn)?;
+                    // The sum of the negated arguments is the negated sum of the arguments
+                    if a.checked_add(b).and_then(|sum| sum.checked_neg()).is_some() {
+                        check_odd_symmetry(&a, &b, naive_midpoint_fn, $checked_neg)?;
+                    }
+                }
+
+                let naive_midpoint_diff_fn =
+                    |a: &$t, b: &$t| unsafe { a.midpoint_via_naive_midpoint_diff(b) };
+                check_idempotence(&a, naive_midpoint_diff_fn)?;
+                if a.does_diff_fit(&b) {
+                    check_within_bounds(&a, &b, naive_midpoint_diff_fn)?;
+                }
+                if let (Some(neg_a), Some(neg_b)) = (($checked_neg)(&a), ($checked_neg)(&b)) {
+                    if a.does_diff_fit(&b) && neg_a.does_diff_fit(&neg_b) {
+                        check_odd_symmetry(&a, &b, naive_midpoint_diff_fn, $checked_neg)?;
+                    }
+                }
+            });
+        };
+    }
+
+    #[test]
+    fn midpoint_of_prim_ints_satisfies_algebraic_properties() {
+        int_properties!(
+            u8,
+            |x: &u8| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt,
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            u16,
+            |x: &u16| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt,
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            u32,
+            |x: &u32| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt,
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            u64,
+            |x: &u64| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt,
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            usize,
+            |x: &usize| x.checked_neg(),
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            u128,
+            |x: &u128| x.checked_neg(),
+            carry_rotate = MidpointViaCarryRotateExt
+        );
+        int_properties!(
+            i8,
+            |x: &i8| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt
+        );
+        int_properties!(
+            i16,
+            |x: &i16| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt
+        );
+        int_properties!(
+            i32,
+            |x: &i32| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt
+        );
+        int_properties!(
+            i64,
+            |x: &i64| x.checked_neg(),
+            promotion = MidpointViaPrimitivePromotionExt
+        );
+        // isize and i128 don't have a primitive promotion
+        int_properties!(isize, |x: &isize| x.checked_neg());
+        int_properties!(i128, |x: &i128| x.checked_neg());
+    }
+
+    macro_rules! float_properties {
+        ($t:ty) => {
+            proptest!(|(a in limit_biased(<$t>::MIN, <$t>::MAX), b in limit_biased(<$t>::MIN, <$t>::MAX))| {
+                // NaN is unordered and so is the midpoint of the opposite infinities
+                prop_assume!(!(a.is_nan() || b.is_nan() || a.is_infinite() && a == -b));
+                let midpoint_fn = <$t>::midpoint_via_float_rescaling;
+                check_within_bounds(&a, &b, midpoint_fn)?;
+                check_idempotence(&a, midpoint_fn)?;
+                check_commutativity(&a, &b, midpoint_fn)?;
+                check_odd_symmetry(&a, &b, midpoint_fn, |x: &$t| Some(-x))?;
+            });
+        };
+    }
+
+    #[test]
+    fn midpoint_of_prim_floats_satisfies_algebraic_properties() {
+        float_properties!(f32);
+        float_properties!(f64);
+    }
+
+    macro_rules! ordered_properties {
+        ($t:ty, $strategy:expr) => {
+            proptest!(|(a in $strategy, b in $strategy)| {
+                // Bitwise operations round down, the C++20 algorithm rounds towards the left argument
+                check_within_bounds(&a, &b, <$t>::midpoint_via_bitwise_ops)?;
+                check_idempotence(&a, <$t>::midpoint_via_bitwise_ops)?;
+                check_commutativity(&a, &b, <$t>::midpoint_via_bitwise_ops)?;
+
+                let cpp_20_midpoint_fn = <$t>::midpoint_via_cpp_20_std_implementation;
+                check_within_bounds(&a, &b, cpp_20_midpoint_fn)?;
+                check_idempotence(&a, cpp_20_midpoint_fn)?;
+            });
+        };
+    }
+
+    #[test]
+    fn midpoint_of_durations_satisfies_algebraic_properties() {
+        ordered_properties!(
+            Duration,
+            (limit_biased(u64::MIN, u64::MAX), 0..1_000_000_000u32)
+                .prop_map(|(secs, nanos)| Duration::new(secs, nanos))
+        );
+    }
+
+    #[test]
+    fn midpoint_of_chars_satisfies_algebraic_properties() {
+        ordered_properties!(char, limit_biased(char::MIN, char::MAX));
+    }
+
+    #[test]
+    fn midpoint_of_ip_addrs_satisfies_algebraic_properties() {
+        ordered_properties!(
+            Ipv4Addr,
+            limit_biased(u32::MIN, u32::MAX).prop_map(Ipv4Addr::from)
+        );
+        ordered_properties!(
+            Ipv6Addr,
+            limit_biased(u128::MIN, u128::MAX).prop_map(Ipv6Addr::from)
+        );
+    }
+
+    // The bounds of an interval are ordered, so the properties depending on the order
+    // of the arguments don't apply to its center
+    fn interval_center<T: Copy + PartialOrd>(a: &T, b: &T) -> T
+    where
+        Interval<T>: IntervalMidpointExt<Bound = T>,
+    {
+        let (lo, hi) = if a <= b { (*a, *b) } else { (*b, *a) };
+        Interval::new(lo, hi).unwrap().center()
+    }
+
+    macro_rules! int_interval_properties {
+        ($($t:ty),+) => {
+            $(
+                proptest!(|(a in limit_biased(<$t>::MIN, <$t>::MAX), b in limit_biased(<$t>::MIN, <$t>::MAX))| {
+                    check_within_bounds(&a, &b, interval_center)?;
+                    check_idempotence(&a, interval_center)?;
+                });
+            )+
+        };
+    }
+
+    macro_rules! float_interval_properties {
+        ($($t:ty),+) => {
+            $(
+                proptest!(|(a in limit_biased(<$t>::MIN, <$t>::MAX), b in limit_biased(<$t>::MIN, <$t>::MAX))| {
+                    // NaN is unordered and so is the midpoint of the opposite infinities
+                    prop_assume!(!(a.is_nan() || b.is_nan() || a.is_infinite() && a == -b));
+                    check_within_bounds(&a, &b, interval_center)?;
+                    check_idempotence(&a, interval_center)?;
+                });
+            )+
+        };
+    }
+
+    #[test]
+    fn center_of_intervals_satisfies_algebraic_properties() {
+        int_interval_properties!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        float_interval_properties!(f32, f64);
+    }
+}
@@ -0,0 +1,254 @@
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedMul};
+
+/// Extension trait providing exact midpoint of [rational numbers](https://docs.rs/num-rational).
+pub trait CheckedRatioMidpointExt: Sized {
+    /// Returns the exact midpoint of two rational numbers or [`None`] when it is not
+    /// representable.
+    ///
+    /// Unlike `(a + b) / 2`, which overflows as soon as the product of the denominators
+    /// does, the computation reduces the denominators by their greatest common divisor
+    /// before combining them and halves the sum of the numerators without overflow.
+    /// When the numerators multiplied by the reduced denominators overflow, the integer
+    /// parts of the numbers are halved separately from their fractional parts. However,
+    /// [`None`] is also returned when, in addition, the least common multiple of the
+    /// denominators overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_rational::Ratio;
+    /// use midpoint::CheckedRatioMidpointExt;
+    ///
+    /// let (a, b) = (Ratio::new(i64::MAX, 2), Ratio::new(i64::MAX - 2, 2));
+    /// assert_eq!(a.checked_midpoint(&b), Some(Ratio::from_integer(i64::MAX / 2)));
+    /// // The denominator of the exact midpoint, 2 * i64::MAX, doesn't fit in i64
+    /// assert_eq!(Ratio::new(1, i64::MAX).checked_midpoint(&Ratio::from_integer(0)), None);
+    /// ```
+    #[must_use]
+    fn checked_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Option<Self>;
+}
+
+impl<T> CheckedRatioMidpointExt for Ratio<T>
+where
+    T: Clone + Integer + CheckedAdd + CheckedMul,
+{
+    fn checked_midpoint(&self /*lhs_ref*/, rhs_ref: &Self) -> Option<Self> {
+        // Ratio keeps the numbers reduced and the denominators positive
+        let (a, b) = (self.numer(), self.denom());
+        let (c, d) = (rhs_ref.numer(), rhs_ref.denom());
+        checked_midpoint_of_parts(a, b, c, d).or_else(|| {
+            let two = T::one() + T::one();
+            // The numerators multiplied by the reduced denominators may overflow even when
+            // the midpoint is representable. Then the integer parts are halved separately:
+            // a/b == q_a + r_a/b, where 0 <= r_a < b, and likewise for c/d
+            let (q_a, r_a) = a.div_mod_floor(b);
+            let (q_c, r_c) = c.div_mod_floor(d);
+            // q_a + q_c == 2*h + s
+            let (h, s) = checked_half_sum(q_a, q_c)?;
+
+            // r_a/b + r_c/d == (x + y) / l, where x, y < l and l is the least common multiple
+            let g = b.gcd(d);
+            let (b_1, d_1) = (b.clone() / g.clone(), d.clone() / g);
+            let l = b_1.checked_mul(d)?;
+            let (x, y) = (r_a * d_1, r_c * b_1);
+            // x + y == k*l + z, where 0 <= z < l
+            let (k, z) = if x >= l.clone() - y.clone() {
+                (T::one(), x - (l.clone() - y))
+            } else {
+                (T::zero(), x + y)
+            };
+
+            // midpoint == h + ((s + k)*l + z) / (2*l) == h + (t*l + z) / (2*l)
+            let (h, t) = if s.clone() + k.clone() == two {
+                (h.checked_add(&T::one())?, T::zero())
+            } else {
+                (h, s + k)
+            };
+            let is_numer_even = if t.is_zero() {
+                z.is_even()
+            } else {
+                (l.clone() - z.clone()).is_even()
+            };
+            let (p, q) = if is_numer_even {
+                // 2*l may overflow but l doesn't
+                let half_numer = if t.is_zero() {
+                    z / two
+                } else {
+                    z.clone() + (l.clone() - z) / two
+                };
+                let g_2 = half_numer.gcd(&l);
+                (half_numer / g_2.clone(), l / g_2)
+            } else {
+                // The odd numerator t*l + z and 2*l have the same common divisors as z and l
+                let g_2 = z.gcd(&l);
+                let denom = (l.clone() / g_2.clone()).checked_mul(&two)?;
+                let numer = if t.is_zero() {
+                    z / g_2
+                } else {
+                    l / g_2.clone() + z / g_2
+                };
+                (numer, denom)
+            };
+
+            // midpoint == h + p/q, where 0 <= p < q. For negative h, h*q may overflow
+            // even when h*q + p doesn't but (h + 1)*q doesn't
+            let numer = if h < T::zero() {
+                (h + T::one())
+                    .checked_mul(&q)?
+                    .checked_add(&(p - q.clone()))?
+            } else {
+                h.checked_mul(&q)?.checked_add(&p)?
+            };
+            Some(Ratio::new_raw(numer, q))
+        })
+    }
+}
+
+/// Returns `(h, r)` such that `x + y == 2*h + r` and `r` is either zero or one, or [`None`]
+/// when `h` overflows.
+fn checked_half_sum<T>(x: T, y: T) -> Option<(T, T)>
+where
+    T: Clone + Integer + CheckedAdd,
+{
+    // The sum is halved without overflow akin to the implementation via bitwise operations
+    let two = T::one() + T::one();
+    let (x_half, x_rem) = x.div_mod_floor(&two);
+    let (y_half, y_rem) = y.div_mod_floor(&two);
+    let rems_sum = x_rem + y_rem;
+    if rems_sum == two {
+        Some((
+            x_half.checked_add(&y_half)?.checked_add(&T::one())?,
+            T::zero(),
+        ))
+    } else {
+        Some((x_half.checked_add(&y_half)?, rems_sum))
+    }
+}
+
+/// Returns the midpoint of a/b and c/d, where the fractions are reduced and the denominators
+/// are positive, or [`None`] when an intermediate result overflows.
+fn checked_midpoint_of_parts<T>(a: &T, b: &T, c: &T, d: &T) -> Option<Ratio<T>>
+where
+    T: Clone + Integer + CheckedAdd + CheckedMul,
+{
+    let two = T::one() + T::one();
+
+    // a/b + c/d == (a*d' + c*b') / (b'*d'*g), where b == b'*g and d == d'*g
+    let g = b.gcd(d);
+    let (b_1, d_1) = (b.clone() / g.clone(), d.clone() / g.clone());
+    let (x, y) = (a.checked_mul(&d_1)?, c.checked_mul(&b_1)?);
+
+    // The sum of the numerators x + y == 2*h + r
+    let (h, r) = checked_half_sum(x, y)?;
+
+    // Since a and b are coprime as well as b' and d', the sum of the numerators
+    // and b'*d' are coprime. Therefore, the midpoint can only be reduced by the
+    // divisors of g.
+    if r.is_zero() {
+        // midpoint == h / (b'*d'*g)
+        if h.is_zero() {
+            return Some(Ratio::from_integer(h));
+        }
+        let g_2 = h.gcd(&g);
+        let denom = b_1.checked_mul(&d_1)?.checked_mul(&(g / g_2.clone()))?;
+        Some(Ratio::new_raw(h / g_2, denom))
+    } else {
+        // midpoint == (2*h + 1) / (2*b'*d'*g), where 2*h + 1 may overflow but
+        // its remainder modulo g doesn't
+        let h_mod_g = h.mod_floor(&g);
+        let complement = g.clone() - h_mod_g.clone() - T::one();
+        let numer_mod_g = if h_mod_g >= complement {
+            h_mod_g - complement
+        } else {
+            h_mod_g.clone() + h_mod_g + T::one()
+        };
+        // g_2 is odd as a divisor of the odd numerator
+        let g_2 = numer_mod_g.gcd(&g);
+        // (2*h + 1) / g_2 == 2*(h / g_2) + 1 because g_2 divides 2*h + 1
+        let numer = h
+            .div_floor(&g_2)
+            .checked_mul(&two)?
+            .checked_add(&T::one())?;
+        let denom = b_1
+            .checked_mul(&d_1)?
+            .checked_mul(&(g / g_2))?
+            .checked_mul(&two)?;
+        Some(Ratio::new_raw(numer, denom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CheckedRatioMidpointExt;
+    use num_rational::Ratio;
+
+    #[test]
+    fn checked_midpoint_does_not_overflow_when_sum_of_numerators_does() {
+        let (a, b) = (Ratio::new(u8::MAX, 1), Ratio::new(u8::MAX - 2, 1));
+        assert_eq!(
+            a.checked_midpoint(&b),
+            Some(Ratio::from_integer(u8::MAX - 1))
+        );
+        let (a, b) = (Ratio::new(i8::MIN, 1), Ratio::new(i8::MIN + 2, 1));
+        assert_eq!(
+            a.checked_midpoint(&b),
+            Some(Ratio::from_integer(i8::MIN + 1))
+        );
+        let (a, b) = (Ratio::new(i8::MAX, 7), Ratio::new(i8::MAX - 6, 7));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(124, 7)));
+    }
+
+    #[test]
+    fn checked_midpoint_reduces_result() {
+        let (a, b) = (Ratio::new(1i32, 6), Ratio::new(1, 2));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(1, 3)));
+        let (a, b) = (Ratio::new(1i32, 6), Ratio::new(1, 3));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(1, 4)));
+        let (a, b) = (Ratio::new(5i32, 6), Ratio::new(-5, 6));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::from_integer(0)));
+        let (a, b) = (Ratio::new(-7i32, 15), Ratio::new(2, 5));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(-1, 30)));
+    }
+
+    #[test]
+    fn checked_midpoint_matches_naive_midpoint_when_it_does_not_overflow() {
+        for a_numer in -12i32..=12 {
+            for a_denom in 1..=12 {
+                for b_numer in -12..=12 {
+                    for b_denom in 1..=12 {
+                        let (a, b) = (Ratio::new(a_numer, a_denom), Ratio::new(b_numer, b_denom));
+                        let expected = (a + b) / 2;
+                        let result = a.checked_midpoint(&b).unwrap();
+                        assert_eq!(result, expected);
+                        assert_eq!(result.numer(), expected.numer());
+                        assert_eq!(result.denom(), expected.denom());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_midpoint_does_not_overflow_when_numerators_times_denominators_do() {
+        let (a, b) = (Ratio::new(127i8, 2), Ratio::new(-127, 3));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(127, 12)));
+        let (a, b) = (Ratio::new(127i8, 2), Ratio::new(127, 6));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(127, 3)));
+        let (a, b) = (Ratio::new(-120i8, 1), Ratio::new(110, 3));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(-125, 3)));
+        let (a, b) = (Ratio::new(i8::MIN, 3), Ratio::new(-125, 6));
+        assert_eq!(a.checked_midpoint(&b), Some(Ratio::new(-127, 4)));
+        let (a, b) = (Ratio::new(i8::MIN, 3), Ratio::new(i8::MIN, 9));
+        assert_eq!(a.checked_midpoint(&b), None);
+    }
+
+    #[test]
+    fn checked_midpoint_returns_none_when_result_is_unrepresentable() {
+        let (a, b) = (Ratio::new(1u8, 255), Ratio::new(0, 1));
+        assert_eq!(a.checked_midpoint(&b), None);
+        let (a, b) = (Ratio::new(1u8, 15), Ratio::new(1, 16));
+        assert_eq!(a.checked_midpoint(&b), None);
+    }
+}
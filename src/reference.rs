@@ -0,0 +1,77 @@
+//! Reference model of the midpoint of primitive integers shared by the tests in
+//! `src/verification.rs` and the Kani proof harnesses in `src/kani_proofs.rs`.
+
+use crate::RoundingMode;
+
+pub(crate) trait ReferenceMidpoint: Sized {
+    /// Returns the floor and the ceiling of the exact midpoint of the arguments.
+    fn floor_and_ceil(a: Self, b: Self) -> (Self, Self);
+
+    /// Returns the exact midpoint of the arguments rounded according to `mode`.
+    fn expected(a: Self, b: Self, mode: RoundingMode) -> Self;
+}
+
+macro_rules! impl_expected_fn {
+    () => {
+        // Type limit comparisons are deemed useless for unsigned types
+        // by the compiler but they are necessary for signed types
+        #[allow(unused_comparisons)]
+        fn expected(a: Self, b: Self, mode: RoundingMode) -> Self {
+            let (floor, ceil) = Self::floor_and_ceil(a, b);
+            match mode {
+                RoundingMode::Up => ceil,
+                RoundingMode::Down => floor,
+                RoundingMode::TowardZero if floor < 0 => ceil,
+                RoundingMode::TowardZero => floor,
+                RoundingMode::TowardLhs if a > floor => ceil,
+                RoundingMode::TowardLhs => floor,
+                RoundingMode::TowardRhs if b > floor => ceil,
+                RoundingMode::TowardRhs => floor,
+                RoundingMode::TiesToEven if floor % 2 == 0 => floor,
+                RoundingMode::TiesToEven => ceil,
+            }
+        }
+    };
+}
+
+// Up to 64 bits, the sum of the arguments is computed in i128
+macro_rules! impl_for_widenable_types {
+    ($($t:ty),+) => {
+        $(
+            impl ReferenceMidpoint for $t {
+                fn floor_and_ceil(a: Self, b: Self) -> (Self, Self) {
+                    let sum = a as i128 + b as i128;
+                    (sum.div_euclid(2) as Self, (sum + 1).div_euclid(2) as Self)
+                }
+
+                impl_expected_fn!();
+            }
+        )+
+    };
+}
+
+impl_for_widenable_types!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+// For 128-bit types, the 129-bit sum of the arguments is assembled from
+// the wrapping sum and the carry (or the sign in the case of signed types)
+
+impl ReferenceMidpoint for u128 {
+    fn floor_and_ceil(a: Self, b: Self) -> (Self, Self) {
+        let (wrapping_sum, carry) = a.overflowing_add(b);
+        let floor = (wrapping_sum >> 1) | ((carry as u128) << 127);
+        (floor, floor + (wrapping_sum & 1))
+    }
+
+    impl_expected_fn!();
+}
+
+impl ReferenceMidpoint for i128 {
+    fn floor_and_ceil(a: Self, b: Self) -> (Self, Self) {
+        let (wrapping_sum, overflow) = a.overflowing_add(b);
+        let is_sum_negative = (wrapping_sum < 0) ^ overflow;
+        let floor = (((wrapping_sum as u128) >> 1) | ((is_sum_negative as u128) << 127)) as i128;
+        (floor, floor + (wrapping_sum & 1))
+    }
+
+    impl_expected_fn!();
+}
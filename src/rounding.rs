@@ -0,0 +1,73 @@
+/// Rounding behavior of a midpoint whose exact value is not representable.
+///
+/// The rounding is either absolute (`Up`, `Down`, `TowardZero`), relative to the arguments
+/// (`TowardLhs`, `TowardRhs`), or towards the even candidate (`TiesToEven`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round up (towards `MAX`).
+    Up,
+    /// Round down (towards `MIN`).
+    Down,
+    /// Round towards `0`.
+    TowardZero,
+    /// Round towards the first argument (`lhs`).
+    TowardLhs,
+    /// Round towards the second argument (`rhs`).
+    TowardRhs,
+    /// Round to nearest and, when the exact midpoint lies halfway between two candidates,
+    /// towards the even one.
+    TiesToEven,
+}
+
+impl RoundingMode {
+    /// All rounding modes, e.g. for checking a midpoint in every mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::{ExactMidpointExt, RoundingMode};
+    ///
+    /// for mode in RoundingMode::ALL {
+    ///     assert_eq!(3i32.midpoint_rounded(&3, mode), 3);
+    /// }
+    /// ```
+    pub const ALL: [RoundingMode; 6] = [
+        RoundingMode::Up,
+        RoundingMode::Down,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardLhs,
+        RoundingMode::TowardRhs,
+        RoundingMode::TiesToEven,
+    ];
+
+    /// Returns whether the midpoint rounded this way doesn't depend on the order of
+    /// the arguments, i.e. `midpoint(a, b) == midpoint(b, a)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::RoundingMode;
+    ///
+    /// assert!(RoundingMode::TowardZero.is_commutative());
+    /// assert!(!RoundingMode::TowardLhs.is_commutative());
+    /// ```
+    pub const fn is_commutative(self) -> bool {
+        !matches!(self, RoundingMode::TowardLhs | RoundingMode::TowardRhs)
+    }
+
+    /// Returns whether the midpoint rounded this way commutes with negation, i.e.
+    /// `midpoint(a, b) == -midpoint(-a, -b)` whenever the negations are defined.
+    /// Only rounding up and rounding down lack it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::RoundingMode;
+    ///
+    /// assert!(RoundingMode::TowardLhs.is_odd_symmetric());
+    /// assert!(!RoundingMode::Down.is_odd_symmetric());
+    /// ```
+    pub const fn is_odd_symmetric(self) -> bool {
+        !matches!(self, RoundingMode::Up | RoundingMode::Down)
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::{Instant, SystemTime};
+
+use crate::MidpointViaCpp20StdImplementationExt;
+
+// Neither Instant nor SystemTime expose their raw representation. However, their difference
+// is a Duration, which allows to mirror the C++20 standard library implementation:
+// a > b ? a - (a - b)/2 : a + (b - a)/2
+
+impl MidpointViaCpp20StdImplementationExt for Instant {
+    /// Returns midpoint of two [`Instant`]s using algorithm
+    /// [as suggested for  C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html).
+    /// The result is rounded towards left argument to the nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use midpoint::MidpointViaCpp20StdImplementationExt;
+    ///
+    /// let a = Instant::now();
+    /// let b = a + Duration::from_secs(2);
+    /// assert_eq!(a.midpoint_via_cpp_20_std_implementation(&b), a + Duration::from_secs(1));
+    /// ```
+    fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+        let (a, b) = (*self, *b_ref);
+        if a > b {
+            a - a.duration_since(b) / 2
+        } else {
+            a + b.duration_since(a) / 2
+        }
+    }
+}
+
+impl MidpointViaCpp20StdImplementationExt for SystemTime {
+    /// Returns midpoint of two [`SystemTime`]s using algorithm
+    /// [as suggested for  C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html).
+    /// The result is rounded towards left argument to the nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// use midpoint::MidpointViaCpp20StdImplementationExt;
+    ///
+    /// let a = UNIX_EPOCH + Duration::from_secs(4);
+    /// let b = UNIX_EPOCH;
+    /// assert_eq!(a.midpoint_via_cpp_20_std_implementation(&b), UNIX_EPOCH + Duration::from_secs(2));
+    /// ```
+    fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+        let (a, b) = (*self, *b_ref);
+        // SystemTime::duration_since fails with the (positive) difference b - a when b > a
+        match a.duration_since(b) {
+            Ok(a_sub_b) => a - a_sub_b / 2,
+            Err(err) => a + err.duration() / 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MidpointViaCpp20StdImplementationExt;
+    use core::time::Duration;
+    use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_instant_rounds_towards_left_arg() {
+        let a = Instant::now();
+        let b = a + Duration::from_nanos(3);
+        assert_eq!(
+            a.midpoint_via_cpp_20_std_implementation(&b),
+            a + Duration::from_nanos(1)
+        );
+        assert_eq!(
+            b.midpoint_via_cpp_20_std_implementation(&a),
+            a + Duration::from_nanos(2)
+        );
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_system_time_rounds_towards_left_arg() {
+        let a = UNIX_EPOCH + Duration::new(1, 999_999_999);
+        let b = UNIX_EPOCH + Duration::new(4, 0);
+        assert_eq!(
+            a.midpoint_via_cpp_20_std_implementation(&b),
+            UNIX_EPOCH + Duration::new(2, 999_999_999)
+        );
+        assert_eq!(
+            b.midpoint_via_cpp_20_std_implementation(&a),
+            UNIX_EPOCH + Duration::new(3, 0)
+        );
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_system_time_works_before_unix_epoch() {
+        let a: SystemTime = UNIX_EPOCH - Duration::from_secs(10);
+        let b = UNIX_EPOCH + Duration::from_secs(10);
+        assert_eq!(a.midpoint_via_cpp_20_std_implementation(&b), UNIX_EPOCH);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::RoundingMode;
+
+/// Extension trait providing lane-wise midpoint of integers packed into a wider
+/// unsigned integer, which is computed via [SWAR](https://en.wikipedia.org/wiki/SWAR)
+/// (SIMD within a register) counterpart of the implementation via bitwise operations.
+///
+/// For example, [`u64`] holds 8 lanes of [`u8`] or [`i8`] and 4 lanes of [`u16`]
+/// or [`i16`]. The lanes are independent, so their order is irrelevant.
+///
+/// The lane-wise midpoint takes a handful of scalar operations regardless of
+/// the number of lanes, which makes it suitable for targets without SIMD.
+pub trait MidpointViaSwarExt {
+    /// Rounding behaviors of the lane-wise midpoints. Every lane type has a method per
+    /// rounding behavior, so the trait has no single `ROUNDING` constant.
+    const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::Up, RoundingMode::Down];
+
+    /// Returns the lane-wise midpoint of [`u8`] lanes rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (u32::from_le_bytes([255, 0, 1, 7]), u32::from_le_bytes([255, 255, 2, 2]));
+    /// assert_eq!(a.u8_lanes_midpoint_down(&b).to_le_bytes(), [255, 127, 1, 4]);
+    /// ```
+    #[must_use]
+    fn u8_lanes_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`u8`] lanes rounded up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (u32::from_le_bytes([255, 0, 1, 7]), u32::from_le_bytes([255, 255, 2, 2]));
+    /// assert_eq!(a.u8_lanes_midpoint_up(&b).to_le_bytes(), [255, 128, 2, 5]);
+    /// ```
+    #[must_use]
+    fn u8_lanes_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`i8`] lanes rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (u32::from_le_bytes([0x80, 0x7F, 0xFF, 0]), u32::from_le_bytes([0x7F, 0x7F, 0, 0xFD]));
+    /// // [-128, 127, -1, 0] and [127, 127, 0, -3]
+    /// assert_eq!(a.i8_lanes_midpoint_down(&b).to_le_bytes(), [0xFF, 0x7F, 0xFF, 0xFE]);
+    /// ```
+    #[must_use]
+    fn i8_lanes_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`i8`] lanes rounded up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (u32::from_le_bytes([0x80, 0x7F, 0xFF, 0]), u32::from_le_bytes([0x7F, 0x7F, 0, 0xFD]));
+    /// // [-128, 127, -1, 0] and [127, 127, 0, -3]
+    /// assert_eq!(a.i8_lanes_midpoint_up(&b).to_le_bytes(), [0, 0x7F, 0, 0xFF]);
+    /// ```
+    #[must_use]
+    fn i8_lanes_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`u16`] lanes rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (0xFFFF_0001u32, 0xFFFE_0002u32);
+    /// assert_eq!(a.u16_lanes_midpoint_down(&b), 0xFFFE_0001);
+    /// ```
+    #[must_use]
+    fn u16_lanes_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`u16`] lanes rounded up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// let (a, b) = (0xFFFF_0001u32, 0xFFFE_0002u32);
+    /// assert_eq!(a.u16_lanes_midpoint_up(&b), 0xFFFF_0002);
+    /// ```
+    #[must_use]
+    fn u16_lanes_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`i16`] lanes rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// // [-32768, -1] and [32767, -4]
+    /// let (a, b) = (0x8000_FFFFu32, 0x7FFF_FFFCu32);
+    /// assert_eq!(a.i16_lanes_midpoint_down(&b), 0xFFFF_FFFD);
+    /// ```
+    #[must_use]
+    fn i16_lanes_midpoint_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+
+    /// Returns the lane-wise midpoint of [`i16`] lanes rounded up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaSwarExt;
+    ///
+    /// // [-32768, -1] and [32767, -4]
+    /// let (a, b) = (0x8000_FFFFu32, 0x7FFF_FFFCu32);
+    /// assert_eq!(a.i16_lanes_midpoint_up(&b), 0x0000_FFFE);
+    /// ```
+    #[must_use]
+    fn i16_lanes_midpoint_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self;
+}
+
+macro_rules! impl_lanes_midpoint_fns {
+    ($t:ty, $lane_max:literal, $down:ident, $up:ident, $signed_down:ident, $signed_up:ident) => {
+        fn $down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // Lowest bit of every lane
+            let lsb_mask: $t = <$t>::MAX / $lane_max;
+            let (lhs, rhs) = (*self, *rhs_ref);
+            // a + b == 2 * (a & b) + (a ^ b). The lowest bits are cleared before the shift
+            // so that they don't get shifted into the adjacent lanes. The lane-wise sums
+            // never exceed the lane maximum, so the carries don't cross the lanes either
+            (lhs & rhs) + (((lhs ^ rhs) & !lsb_mask) >> 1)
+        }
+
+        fn $up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let lsb_mask: $t = <$t>::MAX / $lane_max;
+            let (lhs, rhs) = (*self, *rhs_ref);
+            // a + b == 2 * (a | b) - (a ^ b). The lane-wise differences are never
+            // negative, so the borrows don't cross the lanes
+            (lhs | rhs) - (((lhs ^ rhs) & !lsb_mask) >> 1)
+        }
+
+        fn $signed_down(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            // Highest bit of every lane
+            let msb_mask: $t = <$t>::MAX / $lane_max * ($lane_max / 2 + 1);
+            // Flipping the sign bit maps two's complement to offset binary, which
+            // preserves the order. The bias is even, so the rounding is preserved as well
+            let (lhs, rhs) = (*self ^ msb_mask, *rhs_ref ^ msb_mask);
+            lhs.$down(&rhs) ^ msb_mask
+        }
+
+        fn $signed_up(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+            let msb_mask: $t = <$t>::MAX / $lane_max * ($lane_max / 2 + 1);
+            let (lhs, rhs) = (*self ^ msb_mask, *rhs_ref ^ msb_mask);
+            lhs.$up(&rhs) ^ msb_mask
+        }
+    };
+}
+
+macro_rules! impl_for_lane_containers {
+    ($($t:ty),+) => {
+        $(
+            impl MidpointViaSwarExt for $t {
+                impl_lanes_midpoint_fns!(
+                    $t,
+                    0xFF,
+                    u8_lanes_midpoint_down,
+                    u8_lanes_midpoint_up,
+                    i8_lanes_midpoint_down,
+                    i8_lanes_midpoint_up
+                );
+                impl_lanes_midpoint_fns!(
+                    $t,
+                    0xFFFF,
+                    u16_lanes_midpoint_down,
+                    u16_lanes_midpoint_up,
+                    i16_lanes_midpoint_down,
+                    i16_lanes_midpoint_up
+                );
+            }
+        )+
+    };
+}
+
+impl_for_lane_containers!(u32, u64, u128);
+
+#[cfg(all(test, feature = "bitwise", feature = "exact"))]
+mod tests {
+    use crate::{ExactMidpointExt, MidpointViaBitwiseOpsExt, MidpointViaSwarExt};
+
+    // Values covering the lane boundaries as well as arbitrary bit patterns
+    const SAMPLES: [u128; 8] = [
+        0,
+        u128::MAX,
+        0x8080_8080_8080_8080_8080_8080_8080_8080,
+        0x7F7F_7F7F_7F7F_7F7F_7F7F_7F7F_7F7F_7F7F,
+        0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+        0xFF00_FF01_00FF_01FE_8000_7FFF_8001_7FFE,
+        0xDEAD_BEEF_CAFE_BABE_0BAD_F00D_D15E_A5E5,
+        0x0101_0101_0101_0101_0101_0101_0101_0101,
+    ];
+
+    macro_rules! assert_lanes {
+        ($t:ty, $lane:ty, $swar_fn:ident, $lane_fn:expr) => {
+            for a in SAMPLES.map(|x| x as $t) {
+                for b in SAMPLES.map(|x| x.rotate_left(40) as $t) {
+                    let result = a.$swar_fn(&b);
+                    for shift in (0..<$t>::BITS).step_by(<$lane>::BITS as usize) {
+                        let lane = |x: $t| (x >> shift) as $lane;
+                        let expected: $lane = $lane_fn(&lane(a), &lane(b));
+                        assert_eq!(lane(result), expected, "{a:#x} {b:#x} {shift}");
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! assert_all_lanes {
+        ($t:ty) => {
+            // Unsigned lanes are rounded down by the implementation via bitwise operations
+            assert_lanes!($t, u8, u8_lanes_midpoint_down, u8::midpoint_via_bitwise_ops);
+            assert_lanes!(
+                $t,
+                u16,
+                u16_lanes_midpoint_down,
+                u16::midpoint_via_bitwise_ops
+            );
+            assert_lanes!($t, u8, u8_lanes_midpoint_up, |a: &u8, b: &u8| {
+                a.midpoint_via_bitwise_ops(b) + ((a ^ b) & 1)
+            });
+            assert_lanes!($t, u16, u16_lanes_midpoint_up, |a: &u16, b: &u16| {
+                a.midpoint_via_bitwise_ops(b) + ((a ^ b) & 1)
+            });
+            assert_lanes!($t, i8, i8_lanes_midpoint_down, |a: &i8, b: &i8| {
+                a.exact_midpoint(b).floor()
+            });
+            assert_lanes!($t, i8, i8_lanes_midpoint_up, |a: &i8, b: &i8| {
+                a.exact_midpoint(b).ceil()
+            });
+            assert_lanes!($t, i16, i16_lanes_midpoint_down, |a: &i16, b: &i16| {
+                a.exact_midpoint(b).floor()
+            });
+            assert_lanes!($t, i16, i16_lanes_midpoint_up, |a: &i16, b: &i16| {
+                a.exact_midpoint(b).ceil()
+            });
+        };
+    }
+
+    #[test]
+    fn lanes_midpoint_matches_per_lane_midpoint_for_u32() {
+        assert_all_lanes!(u32);
+    }
+
+    #[test]
+    fn lanes_midpoint_matches_per_lane_midpoint_for_u64() {
+        assert_all_lanes!(u64);
+    }
+
+    #[test]
+    fn lanes_midpoint_matches_per_lane_midpoint_for_u128() {
+        assert_all_lanes!(u128);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "bitwise")]
+use crate::MidpointViaBitwiseOpsExt;
+#[cfg(feature = "cpp_20_std")]
+use crate::MidpointViaCpp20StdImplementationExt;
+
+// Valid chars are Unicode scalar values, i.e. code points in 0..=0x10FFFF except
+// the surrogates U+D800..=U+DFFF. Midpoints are computed over the indices of chars
+// in the sequence of scalar values where the surrogate gap is skipped.
+const SURROGATES_START: u32 = 0xD800;
+const SURROGATES_LEN: u32 = 0x800;
+
+const fn scalar_value_index(c: char) -> u32 {
+    let c = c as u32;
+    if c < SURROGATES_START {
+        c
+    } else {
+        c - SURROGATES_LEN
+    }
+}
+
+const fn char_from_scalar_value_index(i: u32) -> char {
+    let c = if i < SURROGATES_START {
+        i
+    } else {
+        i + SURROGATES_LEN
+    };
+    // SAFETY: the index is always between the indices of two chars, so it is mapped
+    // back onto a code point that is neither a surrogate nor greater than char::MAX
+    unsafe { char::from_u32_unchecked(c) }
+}
+
+#[cfg(feature = "bitwise")]
+impl MidpointViaBitwiseOpsExt for char {
+    /// Returns midpoint of two [`char`]s in the sequence of Unicode scalar values
+    /// (where U+D800..=U+DFFF are skipped) using algorithm using
+    /// [bitwise operations](https://en.wikipedia.org/wiki/Bitwise_operation).
+    /// The result is rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaBitwiseOpsExt;
+    ///
+    /// let result = '\u{D7FF}'.midpoint_via_bitwise_ops(&'\u{E001}');
+    /// assert_eq!(result, '\u{E000}');
+    /// ```
+    fn midpoint_via_bitwise_ops(&self /*lhs_ref*/, rhs_ref: &Self) -> Self {
+        let (lhs, rhs) = (scalar_value_index(*self), scalar_value_index(*rhs_ref));
+        char_from_scalar_value_index(lhs.midpoint_via_bitwise_ops(&rhs))
+    }
+}
+
+#[cfg(feature = "cpp_20_std")]
+impl MidpointViaCpp20StdImplementationExt for char {
+    /// Returns midpoint of two [`char`]s in the sequence of Unicode scalar values
+    /// (where U+D800..=U+DFFF are skipped) using algorithm
+    /// [as suggested for  C++20 standard library](http://www.open-std.org/jtc1/sc22/wg21/docs/papers/2019/p0811r3.html).
+    /// The result is rounded towards left argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midpoint::MidpointViaCpp20StdImplementationExt;
+    ///
+    /// let result = '\u{E001}'.midpoint_via_cpp_20_std_implementation(&'\u{D7FE}');
+    /// assert_eq!(result, '\u{E000}');
+    /// ```
+    fn midpoint_via_cpp_20_std_implementation(&self /*a_ref*/, b_ref: &Self) -> Self {
+        let (a, b) = (scalar_value_index(*self), scalar_value_index(*b_ref));
+        char_from_scalar_value_index(a.midpoint_via_cpp_20_std_implementation(&b))
+    }
+}
+
+#[cfg(all(test, feature = "bitwise", feature = "cpp_20_std"))]
+mod tests {
+    use crate::{MidpointViaBitwiseOpsExt, MidpointViaCpp20StdImplementationExt};
+
+    #[test]
+    fn midpoint_via_bitwise_ops_for_char_skips_surrogates() {
+        assert_eq!('\u{D7FF}'.midpoint_via_bitwise_ops(&'\u{E000}'), '\u{D7FF}');
+        assert_eq!('\u{D7FE}'.midpoint_via_bitwise_ops(&'\u{E001}'), '\u{D7FF}');
+        assert_eq!('\u{0}'.midpoint_via_bitwise_ops(&char::MAX), '\u{883FF}');
+    }
+
+    #[test]
+    fn midpoint_via_cpp_20_std_implementation_for_char_rounds_towards_left_arg() {
+        assert_eq!(
+            '\u{D7FF}'.midpoint_via_cpp_20_std_implementation(&'\u{E000}'),
+            '\u{D7FF}'
+        );
+        assert_eq!(
+            '\u{E000}'.midpoint_via_cpp_20_std_implementation(&'\u{D7FF}'),
+            '\u{E000}'
+        );
+    }
+
+    #[test]
+    fn midpoints_of_chars_are_never_surrogates() {
+        for hi in '\u{E000}'..='\u{E7FF}' {
+            let lo = '\u{D000}';
+            let mid = lo.midpoint_via_bitwise_ops(&hi) as u32;
+            assert!(!(0xD800..=0xDFFF).contains(&mid));
+            let mid = hi.midpoint_via_cpp_20_std_implementation(&lo) as u32;
+            assert!(!(0xD800..=0xDFFF).contains(&mid));
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Verification of the midpoint algorithms for primitive integers against a reference model.
+//!
+//! For 8- and 16-bit types, all pairs of arguments are checked. For wider types, pseudorandom
+//! pairs biased towards the type limits are checked.
+
+use crate::naive_midpoint_diff::NaiveMidpointDiffPrecondition;
+use crate::reference::ReferenceMidpoint;
+use crate::xorshift::XorShift64;
+#[cfg(feature = "constant_time")]
+use crate::ConstantTimeMidpointExt;
+use crate::{
+    ExactMidpointExt, MidpointViaAbsDiffExt, MidpointViaBitwiseOpsExt, MidpointViaCarryRotateExt,
+    MidpointViaCpp20StdImplementationExt, MidpointViaDietzFormulaExt,
+    MidpointViaNaiveMidpointDiffExt, MidpointViaOverflowingAddExt,
+    MidpointViaPrimitivePromotionExt, NaiveMidpointExt, RoundingMode,
+};
+
+macro_rules! impl_check_fn {
+    (
+        $fn_name:ident,
+        $t:ty
+        $(, promotion = $promotion_trait:ident)?
+        $(, carry_rotate = $carry_rotate_trait:ident)?
+    ) => {
+        // Type limit comparisons are deemed useless for unsigned types
+        // by the compiler but they are necessary for signed types
+        #[allow(unused_comparisons)]
+        fn $fn_name(a: $t, b: $t) {
+            let (floor, ceil) = <$t>::floor_and_ceil(a, b);
+            let expected = |mode: RoundingMode| <$t>::expected(a, b, mode);
+
+            let exact = a.exact_midpoint(&b);
+            assert_eq!((exact.floor(), exact.is_half()), (floor, floor != ceil), "{a} {b}");
+            for mode in RoundingMode::ALL {
+                let result = a.midpoint_rounded(&b, mode);
+                assert_eq!(result, expected(mode), "{mode:?} {a} {b}");
+                #[cfg(feature = "constant_time")]
+                assert_eq!(
+                    a.ct_midpoint_rounded(&b, mode),
+                    expected(mode),
+                    "constant time {mode:?} {a} {b}"
+                );
+            }
+
+            let result = a.midpoint_via_bitwise_ops(&b);
+            assert_eq!(result, expected(RoundingMode::TowardZero), "bitwise {a} {b}");
+            let result = a.midpoint_via_cpp_20_std_implementation(&b);
+            assert_eq!(result, expected(RoundingMode::TowardLhs), "cpp20 {a} {b}");
+            let result = a.midpoint_via_dietz_formula(&b);
+            assert_eq!(result, expected(RoundingMode::Down), "dietz {a} {b}");
+            let result = a.midpoint_via_overflowing_add(&b);
+            assert_eq!(result, expected(RoundingMode::Down), "overflowing add {a} {b}");
+            let result = a.midpoint_via_abs_diff(&b);
+            assert_eq!(result, expected(RoundingMode::Down), "abs diff {a} {b}");
+            $(
+                let result = <$t as $promotion_trait>::midpoint_via_primitive_promotion(&a, &b);
+                assert_eq!(result, expected(RoundingMode::TowardZero), "promotion {a} {b}");
+            )?
+            $(
+                let result = <$t as $carry_rotate_trait>::midpoint_via_carry_rotate(&a, &b);
+                assert_eq!(result, expected(RoundingMode::Down), "carry rotate {a} {b}");
+            )?
+
+            // The unsafe implementations are checked only when their preconditions hold
+            if a.checked_add(b).is_some() {
+                let result = unsafe { a.naive_midpoint(&b) };
+                assert_eq!(result, expected(RoundingMode::TowardZero), "naive {a} {b}");
+            }
+            if a.does_diff_fit(&b) {
+                let result = unsafe { a.midpoint_via_naive_midpoint_diff(&b) };
+                assert_eq!(result, expected(RoundingMode::TowardLhs), "naive diff {a} {b}");
+            }
+        }
+    };
+}
+
+impl_check_fn!(
+    check_u8,
+    u8,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+impl_check_fn!(check_i8, i8, promotion = MidpointViaPrimitivePromotionExt);
+impl_check_fn!(
+    check_u32,
+    u32,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+impl_check_fn!(check_i32, i32, promotion = MidpointViaPrimitivePromotionExt);
+impl_check_fn!(
+    check_u64,
+    u64,
+    promotion = MidpointViaPrimitivePromotionExt,
+    carry_rotate = MidpointViaCarryRotateExt
+);
+impl_check_fn!(check_i64, i64, promotion = MidpointViaPrimitivePromotionExt);
+// usize, isize, u128 and i128 don't have a primitive promotion
+impl_check_fn!(check_usize, usize, carry_rotate = MidpointViaCarryRotateExt);
+impl_check_fn!(check_isize, isize);
+impl_check_fn!(check_u128, u128, carry_rotate = MidpointViaCarryRotateExt);
+impl_check_fn!(check_i128, i128);
+
+const RANDOM_PAIRS_COUNT: usize = 100_000;
+
+macro_rules! check_random_pairs {
+    ($check_fn:ident, $t:ty) => {
+        let mut rng = XorShift64(0x2545_F491_4F6C_DD1D);
+        let mut next = || {
+            let bits = rng.next_u128() as $t;
+            let small = (rng.next_u64() % 4) as $t;
+            // Overflow-related bugs tend to manifest near the type limits
+            match rng.next_u64() % 4 {
+                0 => bits,
+                1 => bits >> (rng.next_u64() % <$t>::BITS as u64),
+                2 => <$t>::MIN.wrapping_add(small),
+                _ => <$t>::MAX.wrapping_sub(small),
+            }
+        };
+        for _ in 0..RANDOM_PAIRS_COUNT {
+            let (a, b) = (next(), next());
+            $check_fn(a, b);
+        }
+    };
+}
+
+#[test]
+fn all_pairs_of_u8_match_reference() {
+    for a in u8::MIN..=u8::MAX {
+        for b in u8::MIN..=u8::MAX {
+            check_u8(a, b);
+        }
+    }
+}
+
+#[test]
+fn all_pairs_of_i8_match_reference() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            check_i8(a, b);
+        }
+    }
+}
+
+// The 2^32 pairs of 16-bit integers are checked for every algorithm by a separate test,
+// so that the tests can run in parallel. Algorithms implemented only for one of the types
+// are restricted to it via `for`.
+macro_rules! all_pairs_of_16_bit_ints_match_reference {
+    ($(
+        $test_name:ident: $mode:ident, |$a:ident, $b:ident| $midpoint:expr
+        $(, if $precondition:expr)?
+        $(, for $only_t:ty)?;
+    )+) => {
+        $(
+            #[test]
+            fn $test_name() {
+                all_pairs_of_16_bit_ints_match_reference!(
+                    @types [$($only_t)?] $mode, |$a, $b| $midpoint $(, if $precondition)?
+                );
+            }
+        )+
+    };
+    (@types [] $mode:ident, |$a:ident, $b:ident| $midpoint:expr $(, if $precondition:expr)?) => {
+        all_pairs_of_16_bit_ints_match_reference!(
+            @check u16, $mode, |$a, $b| $midpoint $(, if $precondition)?
+        );
+        all_pairs_of_16_bit_ints_match_reference!(
+            @check i16, $mode, |$a, $b| $midpoint $(, if $precondition)?
+        );
+    };
+    (@types [$t:ty] $mode:ident, |$a:ident, $b:ident| $midpoint:expr $(, if $precondition:expr)?) => {
+        all_pairs_of_16_bit_ints_match_reference!(
+            @check $t, $mode, |$a, $b| $midpoint $(, if $precondition)?
+        );
+    };
+    (@check $t:ty, $mode:ident, |$a:ident, $b:ident| $midpoint:expr $(, if $precondition:expr)?) => {
+        for $a in <$t>::MIN..=<$t>::MAX {
+            for $b in <$t>::MIN..=<$t>::MAX {
+                $(if !$precondition { continue; })?
+                let expected = <$t>::expected($a, $b, RoundingMode::$mode);
+                assert_eq!($midpoint, expected, "{} {}", $a, $b);
+            }
+        }
+    };
+}
+
+all_pairs_of_16_bit_ints_match_reference!(
+    all_pairs_of_16_bit_ints_match_reference_via_bitwise_ops: TowardZero,
+        |a, b| a.midpoint_via_bitwise_ops(&b);
+    all_pairs_of_16_bit_ints_match_reference_via_cpp_20_std_implementation: TowardLhs,
+        |a, b| a.midpoint_via_cpp_20_std_implementation(&b);
+    all_pairs_of_16_bit_ints_match_reference_via_dietz_formula: Down,
+        |a, b| a.midpoint_via_dietz_formula(&b);
+    all_pairs_of_16_bit_ints_match_reference_via_overflowing_add: Down,
+        |a, b| a.midpoint_via_overflowing_add(&b);
+    all_pairs_of_16_bit_ints_match_reference_via_abs_diff: Down,
+        |a, b| a.midpoint_via_abs_diff(&b);
+    all_pairs_of_16_bit_ints_match_reference_via_primitive_promotion: TowardZero,
+        |a, b| MidpointViaPrimitivePromotionExt::midpoint_via_primitive_promotion(&a, &b);
+    all_pairs_of_16_bit_ints_match_reference_via_carry_rotate: Down,
+        |a, b| a.midpoint_via_carry_rotate(&b),
+        for u16;
+    // The unsafe implementations are checked only when their preconditions hold
+    all_pairs_of_16_bit_ints_match_reference_via_naive_midpoint: TowardZero,
+        |a, b| unsafe { a.naive_midpoint(&b) },
+        if a.checked_add(b).is_some();
+    all_pairs_of_16_bit_ints_match_reference_via_naive_midpoint_diff: TowardLhs,
+        |a, b| unsafe { a.midpoint_via_naive_midpoint_diff(&b) },
+        if a.does_diff_fit(&b);
+);
+
+// The rounding modes of the midpoints supporting all of them are checked
+// in a single pass over the 2^32 pairs
+macro_rules! all_pairs_of_16_bit_ints_match_reference_in_all_rounding_modes {
+    ($(
+        $(#[$attr:meta])*
+        $test_name:ident: |$a:ident, $b:ident, $mode:ident| $midpoint:expr;
+    )+) => {
+        $(
+            $(#[$attr])*
+            #[test]
+            fn $test_name() {
+                all_pairs_of_16_bit_ints_match_reference_in_all_rounding_modes!(
+                    @check u16, |$a, $b, $mode| $midpoint
+                );
+                all_pairs_of_16_bit_ints_match_reference_in_all_rounding_modes!(
+                    @check i16, |$a, $b, $mode| $midpoint
+                );
+            }
+        )+
+    };
+    (@check $t:ty, |$a:ident, $b:ident, $mode:ident| $midpoint:expr) => {
+        for $a in <$t>::MIN..=<$t>::MAX {
+            for $b in <$t>::MIN..=<$t>::MAX {
+                for $mode in RoundingMode::ALL {
+                    let expected = <$t>::expected($a, $b, $mode);
+                    assert_eq!($midpoint, expected, "{:?} {} {}", $mode, $a, $b);
+                }
+            }
+        }
+    };
+}
+
+// The exact midpoint itself is pinned down by the midpoints rounded up and down
+all_pairs_of_16_bit_ints_match_reference_in_all_rounding_modes!(
+    all_pairs_of_16_bit_ints_match_reference_when_rounded:
+        |a, b, mode| a.midpoint_rounded(&b, mode);
+    #[cfg(feature = "constant_time")]
+    all_pairs_of_16_bit_ints_match_reference_via_constant_time_midpoints:
+        |a, b, mode| a.ct_midpoint_rounded(&b, mode);
+);
+
+#[test]
+fn random_pairs_of_32_bit_ints_match_reference() {
+    check_random_pairs!(check_u32, u32);
+    check_random_pairs!(check_i32, i32);
+}
+
+#[test]
+fn random_pairs_of_64_bit_ints_match_reference() {
+    check_random_pairs!(check_u64, u64);
+    check_random_pairs!(check_i64, i64);
+    check_random_pairs!(check_usize, usize);
+    check_random_pairs!(check_isize, isize);
+}
+
+#[test]
+fn random_pairs_of_128_bit_ints_match_reference() {
+    check_random_pairs!(check_u128, u128);
+    check_random_pairs!(check_i128, i128);
+}
@@ -0,0 +1,20 @@
+//! [Xorshift](https://en.wikipedia.org/wiki/Xorshift) pseudorandom number generator shared
+//! by the tests in `src/verification.rs` and the benchmarks in `benches/input_distributions.rs`.
+//! It keeps the inputs identical across runs and free of dependencies.
+
+pub(crate) struct XorShift64(pub(crate) u64);
+
+impl XorShift64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    pub(crate) fn next_u128(&mut self) -> u128 {
+        (self.next_u64() as u128) << 64 | self.next_u64() as u128
+    }
+}
@@ -0,0 +1,295 @@
+//! Inspection of the code generated for the midpoint algorithms.
+//!
+//! The library is compiled in release mode with `--cfg midpoint_codegen_probes`,
+//! which exports a function with a stable symbol name for every inspected algorithm and
+//! type (see `src/codegen_probes.rs`), and the emitted assembly of every such function
+//! is checked.
+//!
+//! The tests check the performance claims made in DESIGN.md locally: the algorithms
+//! don't branch (unless stated otherwise), don't divide, and don't exceed the
+//! instruction count ceilings. The ceilings leave some headroom over the code generated
+//! by the compiler at the time of writing, so that only the significant regressions fail.
+//!
+//! The checks are specific to the instruction set, so they run only on `x86_64`.
+#![cfg(target_arch = "x86_64")]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+const PRIM_INTS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+const UNSIGNED_INTS: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize"];
+// Primitive integers with primitive promotion
+const PROMOTABLE_INTS: &[&str] = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"];
+
+struct Expectation {
+    algorithm: &'static str,
+    types: &'static [&'static str],
+    is_branchless: bool,
+    // Instruction count ceilings for the types up to 64 bits and for the 128-bit types,
+    // including `ret`
+    max_instructions: usize,
+    max_instructions_for_128_bits: usize,
+}
+
+const EXPECTATIONS: &[Expectation] = &[
+    Expectation {
+        algorithm: "naive_midpoint",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 8,
+        max_instructions_for_128_bits: 12,
+    },
+    Expectation {
+        algorithm: "midpoint_via_primitive_promotion",
+        types: PROMOTABLE_INTS,
+        is_branchless: true,
+        max_instructions: 14,
+        max_instructions_for_128_bits: 0,
+    },
+    Expectation {
+        algorithm: "midpoint_via_bitwise_ops",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 14,
+        max_instructions_for_128_bits: 22,
+    },
+    Expectation {
+        algorithm: "midpoint_via_naive_midpoint_diff",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 10,
+        max_instructions_for_128_bits: 16,
+    },
+    // As explained in DESIGN.md, the implementation branches on `a > b`
+    Expectation {
+        algorithm: "midpoint_via_cpp_20_std_implementation",
+        types: PRIM_INTS,
+        is_branchless: false,
+        max_instructions: 18,
+        max_instructions_for_128_bits: 30,
+    },
+    Expectation {
+        algorithm: "midpoint_via_dietz_formula",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 8,
+        max_instructions_for_128_bits: 14,
+    },
+    Expectation {
+        algorithm: "midpoint_via_overflowing_add",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 10,
+        max_instructions_for_128_bits: 14,
+    },
+    Expectation {
+        algorithm: "midpoint_via_abs_diff",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 16,
+        max_instructions_for_128_bits: 26,
+    },
+    // The instructions are written via inline assembly
+    Expectation {
+        algorithm: "midpoint_via_carry_rotate",
+        types: UNSIGNED_INTS,
+        is_branchless: true,
+        max_instructions: 4,
+        max_instructions_for_128_bits: 7,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_up",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 12,
+        max_instructions_for_128_bits: 20,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_down",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 8,
+        max_instructions_for_128_bits: 14,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_toward_zero",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 14,
+        max_instructions_for_128_bits: 22,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_toward_lhs",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 26,
+        max_instructions_for_128_bits: 36,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_toward_rhs",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 26,
+        max_instructions_for_128_bits: 36,
+    },
+    Expectation {
+        algorithm: "ct_midpoint_ties_to_even",
+        types: PRIM_INTS,
+        is_branchless: true,
+        max_instructions: 14,
+        max_instructions_for_128_bits: 20,
+    },
+];
+
+/// Compiles the library with the probes and returns the path to the emitted assembly
+fn emit_asm() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // A separate target directory keeps the probes from invalidating the regular build
+    let target_dir = manifest_dir.join("target").join("codegen-probes");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .args(["rustc", "--release", "--lib", "--"])
+        .args(["--emit", "asm", "-C", "codegen-units=1"])
+        .args(["--cfg", "midpoint_codegen_probes"])
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to compile the probes");
+
+    std::fs::read_dir(target_dir.join("release").join("deps"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let file_name = path.file_name().unwrap().to_string_lossy();
+            file_name.starts_with("midpoint-") && file_name.ends_with(".s")
+        })
+        .max_by_key(|path| path.metadata().unwrap().modified().unwrap())
+        .expect("no assembly was emitted")
+}
+
+/// Returns the instructions of every exported probe, keyed by its symbol name
+fn parse_probes(asm: &str) -> HashMap<String, Vec<String>> {
+    let mut probes = HashMap::new();
+    let mut current: Option<(String, Vec<String>)> = None;
+    let mut aliases = Vec::new();
+    for line in asm.lines() {
+        let line = line.trim();
+        // Identical functions are merged, e.g. the probe for `usize` becomes
+        // `midpoint_probe_..._usize = midpoint_probe_..._u64`
+        if let Some((alias, target)) = line.split_once(" = ") {
+            if alias.starts_with("midpoint_probe_") {
+                aliases.push((alias.to_owned(), target.to_owned()));
+                continue;
+            }
+        }
+        if let Some(label) = line.strip_suffix(':') {
+            if label.starts_with("midpoint_probe_") {
+                current = Some((label.to_owned(), Vec::new()));
+                continue;
+            }
+        }
+        if line.starts_with(".Lfunc_end") || line == ".cfi_endproc" {
+            if let Some((name, instructions)) = current.take() {
+                probes.insert(name, instructions);
+            }
+            continue;
+        }
+        if let Some((_, instructions)) = current.as_mut() {
+            // Labels, directives and comments are not instructions
+            if !(line.is_empty() || line.ends_with(':') || line.starts_with(['.', '#'])) {
+                instructions.push(line.to_owned());
+            }
+        }
+    }
+    for (alias, target) in aliases {
+        let instructions = probes[&target].clone();
+        probes.insert(alias, instructions);
+    }
+    probes
+}
+
+/// The probes are compiled once and shared by the tests
+fn probes() -> &'static HashMap<String, Vec<String>> {
+    static PROBES: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
+    PROBES.get_or_init(|| parse_probes(&std::fs::read_to_string(emit_asm()).unwrap()))
+}
+
+fn probe(algorithm: &str, t: &str) -> &'static [String] {
+    let name = format!("midpoint_probe_{algorithm}_{t}");
+    probes()
+        .get(&name)
+        .unwrap_or_else(|| panic!("{name} is not found in the assembly"))
+}
+
+fn mnemonic(instruction: &str) -> &str {
+    instruction.split_whitespace().next().unwrap()
+}
+
+/// Conditional jumps, e.g. `jne`, as opposed to the unconditional `jmp`
+fn is_conditional_jump(instruction: &str) -> bool {
+    let mnemonic = mnemonic(instruction);
+    mnemonic.starts_with('j') && mnemonic != "jmp"
+}
+
+/// Division instructions, i.e. `div`, `idiv` and their floating-point counterparts
+fn is_division(instruction: &str) -> bool {
+    mnemonic(instruction).contains("div")
+}
+
+#[test]
+fn branchless_algorithms_have_no_conditional_jumps() {
+    for expectation in EXPECTATIONS.iter().filter(|e| e.is_branchless) {
+        for t in expectation.types {
+            let jumps: Vec<_> = probe(expectation.algorithm, t)
+                .iter()
+                .filter(|instruction| is_conditional_jump(instruction))
+                .collect();
+            assert!(
+                jumps.is_empty(),
+                "{} for {t} has conditional jumps: {jumps:?}",
+                expectation.algorithm
+            );
+        }
+    }
+}
+
+#[test]
+fn algorithms_have_no_division() {
+    for expectation in EXPECTATIONS {
+        for t in expectation.types {
+            let divisions: Vec<_> = probe(expectation.algorithm, t)
+                .iter()
+                .filter(|instruction| is_division(instruction))
+                .collect();
+            assert!(
+                divisions.is_empty(),
+                "{} for {t} has divisions: {divisions:?}",
+                expectation.algorithm
+            );
+        }
+    }
+}
+
+#[test]
+fn algorithms_dont_exceed_instruction_count_ceilings() {
+    for expectation in EXPECTATIONS {
+        for t in expectation.types {
+            let instructions = probe(expectation.algorithm, t);
+            let ceiling = if t.ends_with("128") {
+                expectation.max_instructions_for_128_bits
+            } else {
+                expectation.max_instructions
+            };
+            assert!(
+                instructions.len() <= ceiling,
+                "{} for {t} has {} instructions, which exceeds {ceiling}: {instructions:#?}",
+                expectation.algorithm,
+                instructions.len()
+            );
+        }
+    }
+}